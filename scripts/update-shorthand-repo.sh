@@ -12,7 +12,7 @@ cat > src/shorthand_list.rs <<EOF
 // This file is generated by scripts/update-shorthand-repo.sh
 // Do not edit this file manually
 
-pub const SHORTHAND_LIST: [(&str, &str); $num_plugins] = [
+pub static SHORTHAND_LIST: [(&str, &str); $num_plugins] = [
     // rtx custom aliases
     ("node", "https://github.com/asdf-vm/asdf-nodejs.git"),
 
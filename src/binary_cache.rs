@@ -0,0 +1,321 @@
+use std::fmt::{Display, Formatter};
+use std::fs::{create_dir_all, remove_dir_all, File};
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use duct::cmd;
+use serde_derive::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::cli::version::{ARCH, OS};
+use crate::config::Settings;
+use crate::env;
+use crate::git::Git;
+use crate::runtimes::{RuntimeVersion, EXEC_ENV_CACHE_FILENAME};
+
+/// A shared cache of finished installs, e.g.: a directory on NFS or an http server.
+///
+/// Each install is stored as a tarball of its install directory next to a json file with the
+/// metadata it was built with. An install is only reused if the plugin is at the same git sha
+/// and the os, arch and install path match:
+///
+///   <CACHE>/<PLUGIN>/<VERSION>/<OS>-<ARCH>-<PLUGIN_SHA>.tar.gz
+///   <CACHE>/<PLUGIN>/<VERSION>/<OS>-<ARCH>-<PLUGIN_SHA>.json
+#[derive(Debug, Clone)]
+pub enum BinaryCache {
+    Dir(PathBuf),
+    Http(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Metadata {
+    pub plugin: String,
+    pub version: String,
+    pub plugin_sha: String,
+    pub os: String,
+    pub arch: String,
+    pub install_path: PathBuf,
+    /// sha256 of the tarball
+    pub sha256: String,
+}
+
+impl BinaryCache {
+    pub fn new(location: &str) -> Self {
+        if location.starts_with("http://") || location.starts_with("https://") {
+            Self::Http(location.trim_end_matches('/').to_string())
+        } else {
+            Self::Dir(PathBuf::from(location))
+        }
+    }
+
+    /// the cache configured with `binary_cache` or RTX_BINARY_CACHE, if any
+    pub fn from_settings(settings: &Settings) -> Option<Self> {
+        settings.binary_cache.as_deref().map(Self::new)
+    }
+
+    /// uploads the install of `rtv` to the cache
+    pub fn push(&self, rtv: &RuntimeVersion) -> Result<Metadata> {
+        if !rtv.is_installed() {
+            return Err(eyre!("{rtv} is not installed"));
+        }
+        let mut metadata = expected_metadata(rtv)?.ok_or_else(|| {
+            eyre!("{rtv} cannot be cached, its plugin is not a clean git checkout")
+        })?;
+        let tarball = tmp_path(&metadata, "tar.gz");
+        let result = (|| {
+            create_dir_all(tarball.parent().unwrap())?;
+            // the exec-env output depends on the env of this machine
+            let exclude = format!("--exclude=./{EXEC_ENV_CACHE_FILENAME}");
+            cmd!(
+                "tar",
+                "-C",
+                &rtv.install_path,
+                exclude,
+                "-czf",
+                &tarball,
+                "."
+            )
+            .run()?;
+            metadata.sha256 = sha256(&tarball)?;
+            // the metadata is written last so a partial upload is never used
+            self.write(&key(&metadata, "tar.gz"), &tarball)?;
+            let json = tmp_path(&metadata, "json");
+            fs::write(&json, serde_json::to_string_pretty(&metadata)?)?;
+            let result = self.write(&key(&metadata, "json"), &json);
+            let _ = fs::remove_file(&json);
+            result
+        })();
+        let _ = fs::remove_file(&tarball);
+        result.wrap_err_with(|| eyre!("failed to push {rtv} to {self}"))?;
+        Ok(metadata)
+    }
+
+    /// unpacks a cached install of `rtv` into its install path
+    /// returns false if there is no matching install in the cache
+    pub fn pull(&self, rtv: &RuntimeVersion) -> Result<bool> {
+        let expected = match expected_metadata(rtv)? {
+            Some(expected) => expected,
+            None => {
+                debug!("not using binary cache for {rtv}, plugin is not a clean git checkout");
+                return Ok(false);
+            }
+        };
+        let json = tmp_path(&expected, "json");
+        create_dir_all(json.parent().unwrap())?;
+        if !self.read(&key(&expected, "json"), &json)? {
+            debug!("{rtv} is not in binary cache {self}");
+            return Ok(false);
+        }
+        let metadata = fs::read_to_string(&json);
+        let _ = fs::remove_file(&json);
+        let metadata: Metadata = serde_json::from_str(&metadata?)
+            .wrap_err_with(|| eyre!("invalid metadata for {rtv} in {self}"))?;
+        if metadata.install_path != expected.install_path {
+            debug!(
+                "not using binary cache for {rtv}, it was built for {}",
+                metadata.install_path.display()
+            );
+            return Ok(false);
+        }
+        let expected = Metadata {
+            sha256: metadata.sha256.clone(),
+            ..expected
+        };
+        if metadata != expected {
+            return Err(eyre!("metadata for {rtv} in {self} does not match its key"));
+        }
+
+        let tarball = tmp_path(&metadata, "tar.gz");
+        let result = self.unpack(rtv, &metadata, &tarball);
+        let _ = fs::remove_file(&tarball);
+        if result.is_err() {
+            let _ = remove_dir_all(&rtv.install_path);
+        }
+        result.wrap_err_with(|| eyre!("failed to pull {rtv} from {self}"))?;
+        Ok(true)
+    }
+
+    fn unpack(&self, rtv: &RuntimeVersion, metadata: &Metadata, tarball: &Path) -> Result<()> {
+        if !self.read(&key(metadata, "tar.gz"), tarball)? {
+            return Err(eyre!("tarball is missing"));
+        }
+        let sha256 = sha256(tarball)?;
+        if sha256 != metadata.sha256 {
+            return Err(eyre!(
+                "checksum mismatch, expected {} got {sha256}",
+                metadata.sha256
+            ));
+        }
+        let _ = remove_dir_all(&rtv.install_path);
+        create_dir_all(&rtv.install_path)?;
+        cmd!("tar", "-C", &rtv.install_path, "-xzf", tarball).run()?;
+        if !rtv.is_installed() {
+            return Err(eyre!("tarball does not contain a complete install"));
+        }
+        Ok(())
+    }
+
+    /// copies `key` from the cache to `to`, returns false if it is not in the cache
+    fn read(&self, key: &str, to: &Path) -> Result<bool> {
+        match self {
+            Self::Dir(dir) => {
+                let from = dir.join(key);
+                if !from.exists() {
+                    return Ok(false);
+                }
+                fs::copy(&from, to)?;
+                Ok(true)
+            }
+            Self::Http(url) => {
+                let url = format!("{url}/{key}");
+                let status = cmd!("curl", "-sSL", "-o", to, "-w", "%{http_code}", &url).read()?;
+                match status.as_str() {
+                    "200" => Ok(true),
+                    "404" => Ok(false),
+                    _ => Err(eyre!("GET {url} returned HTTP {status}")),
+                }
+            }
+        }
+    }
+
+    fn write(&self, key: &str, from: &Path) -> Result<()> {
+        match self {
+            Self::Dir(dir) => {
+                let to = dir.join(key);
+                create_dir_all(to.parent().unwrap())?;
+                // copy then rename so readers never see a partial file
+                let tmp = to.with_extension(format!("tmp{}", std::process::id()));
+                fs::copy(from, &tmp)?;
+                fs::rename(&tmp, &to)?;
+                Ok(())
+            }
+            Self::Http(url) => {
+                cmd!("curl", "-sSfL", "-T", from, format!("{url}/{key}")).run()?;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for BinaryCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Dir(dir) => write!(f, "{}", dir.display()),
+            Self::Http(url) => write!(f, "{url}"),
+        }
+    }
+}
+
+/// what the metadata of a cached install of `rtv` needs to be, without the checksum
+/// returns None if the plugin's git sha does not identify its scripts
+fn expected_metadata(rtv: &RuntimeVersion) -> Result<Option<Metadata>> {
+    let git = Git::new(rtv.plugin.plugin_path.clone());
+    if rtv.plugin.is_linked() || !git.is_repo() || git.is_dirty()? {
+        return Ok(None);
+    }
+    Ok(Some(Metadata {
+        plugin: rtv.plugin.name.clone(),
+        version: rtv.version.clone(),
+        plugin_sha: git.current_sha()?,
+        os: OS.clone(),
+        arch: ARCH.clone(),
+        install_path: rtv.install_path.clone(),
+        sha256: String::new(),
+    }))
+}
+
+fn key(metadata: &Metadata, ext: &str) -> String {
+    format!(
+        "{}/{}/{}-{}-{}.{ext}",
+        metadata.plugin, metadata.version, metadata.os, metadata.arch, metadata.plugin_sha
+    )
+}
+
+fn tmp_path(metadata: &Metadata, ext: &str) -> PathBuf {
+    env::RTX_TMP_DIR.join("binary_cache").join(format!(
+        "{}-{}-{}.{ext}",
+        metadata.plugin,
+        metadata.version,
+        std::process::id()
+    ))
+}
+
+fn sha256(path: &Path) -> Result<String> {
+    let mut hasher = Sha256::new();
+    io::copy(&mut File::open(path)?, &mut hasher)?;
+    let hash = hasher.finalize();
+    Ok(hash.iter().map(|b| format!("{b:02x}")).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::config::Config;
+    use crate::plugins::{InstallType, Plugin};
+
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(matches!(BinaryCache::new("/mnt/rtx"), BinaryCache::Dir(_)));
+        let cache = BinaryCache::new("https://cache.example.com/rtx/");
+        assert_eq!(cache.to_string(), "https://cache.example.com/rtx");
+    }
+
+    #[test]
+    fn test_key() {
+        let metadata = Metadata {
+            plugin: "python".into(),
+            version: "3.11.1".into(),
+            plugin_sha: "abc123".into(),
+            os: "linux".into(),
+            arch: "x64".into(),
+            install_path: PathBuf::from("/rtx/installs/python/3.11.1"),
+            sha256: String::new(),
+        };
+        assert_eq!(
+            key(&metadata, "tar.gz"),
+            "python/3.11.1/linux-x64-abc123.tar.gz"
+        );
+    }
+
+    #[test]
+    fn test_push_pull() {
+        let (_data, cache_dir, rtv) = install_tiny();
+        let cache = BinaryCache::Dir(cache_dir.path().to_path_buf());
+        let metadata = cache.push(&rtv).unwrap();
+        assert!(cache_dir.path().join(key(&metadata, "tar.gz")).is_file());
+        assert!(cache_dir.path().join(key(&metadata, "json")).is_file());
+
+        remove_dir_all(&rtv.install_path).unwrap();
+        assert!(cache.pull(&rtv).unwrap());
+        assert!(rtv.is_installed());
+        let version = fs::read_to_string(rtv.install_path.join("VERSION")).unwrap();
+        assert_eq!(version, "1.0.1\n");
+    }
+
+    #[test]
+    fn test_pull_checksum_mismatch() {
+        let (_data, cache_dir, rtv) = install_tiny();
+        let cache = BinaryCache::Dir(cache_dir.path().to_path_buf());
+        let metadata = cache.push(&rtv).unwrap();
+        fs::write(cache_dir.path().join(key(&metadata, "tar.gz")), "corrupt").unwrap();
+
+        let err = cache.pull(&rtv).unwrap_err();
+        assert!(format!("{err:#}").contains("checksum mismatch"));
+        assert!(!rtv.install_path.exists());
+    }
+
+    /// installs tiny@1.0.1 into a temporary data dir so the shared installs are not modified
+    fn install_tiny() -> (tempfile::TempDir, tempfile::TempDir, RuntimeVersion) {
+        let data = tempfile::tempdir().unwrap();
+        let cache_dir = tempfile::tempdir().unwrap();
+        let config = Config::load().unwrap();
+        let plugin = Arc::new(Plugin::load(&"tiny".into()).unwrap());
+        let rtv = RuntimeVersion::new_in(plugin, "1.0.1", data.path());
+        rtv.install(InstallType::Version, &config).unwrap();
+        (data, cache_dir, rtv)
+    }
+}
@@ -0,0 +1,69 @@
+use atty::Stream;
+use color_eyre::eyre::{eyre, Result};
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::output::Output;
+use crate::plugins::PluginName;
+use crate::ui::color::Color;
+
+/// Show an alias for a plugin
+///
+/// This is the contents of an alias.<PLUGIN> entry in ~/.config/rtx/config.toml
+/// or an alias provided by the plugin
+#[derive(Debug, clap::Args)]
+#[clap(after_long_help = AFTER_LONG_HELP.as_str(), verbatim_doc_comment)]
+pub struct AliasGet {
+    /// The plugin to show the alias for
+    pub plugin: PluginName,
+    /// The alias to show
+    pub alias: String,
+}
+
+impl Command for AliasGet {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        match config
+            .aliases
+            .get(&self.plugin)
+            .and_then(|a| a.get(&self.alias))
+        {
+            Some(alias) => {
+                rtxprintln!(out, "{}", alias);
+                Ok(())
+            }
+            None => Err(eyre!("Unknown alias: {}", &self.alias)),
+        }
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx alias get nodejs lts/hydrogen
+      18.0.0
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+
+    use crate::test::reset_config;
+    use crate::{assert_cli, assert_cli_err};
+
+    #[test]
+    fn test_alias_get() {
+        reset_config();
+        let stdout = assert_cli!("alias", "get", "shfmt", "my/alias");
+        assert_eq!(stdout.trim(), "3.0");
+    }
+
+    #[test]
+    fn test_alias_get_not_found() {
+        let err = assert_cli_err!("alias", "get", "shfmt", "unknown");
+        assert_display_snapshot!(err, @"Unknown alias: unknown");
+    }
+}
@@ -2,11 +2,16 @@ use clap::Subcommand;
 use color_eyre::eyre::Result;
 
 use crate::cli::command::Command;
+use crate::config::config_file::rtxrc::RTXFile;
 use crate::config::Config;
+use crate::dirs;
 use crate::output::Output;
 use crate::plugins::PluginName;
 
+mod get;
 mod ls;
+mod set;
+mod unset;
 
 #[derive(Debug, clap::Args)]
 #[clap(about = "Manage aliases", visible_alias = "a", alias = "aliases")]
@@ -21,13 +26,19 @@ pub struct Alias {
 
 #[derive(Debug, Subcommand)]
 enum Commands {
+    Get(get::AliasGet),
     Ls(ls::AliasLs),
+    Set(set::AliasSet),
+    Unset(unset::AliasUnset),
 }
 
 impl Commands {
     pub fn run(self, config: Config, out: &mut Output) -> Result<()> {
         match self {
+            Self::Get(cmd) => cmd.run(config, out),
             Self::Ls(cmd) => cmd.run(config, out),
+            Self::Set(cmd) => cmd.run(config, out),
+            Self::Unset(cmd) => cmd.run(config, out),
         }
     }
 }
@@ -41,3 +52,18 @@ impl Command for Alias {
         cmd.run(config, out)
     }
 }
+
+/// the global config or the .rtxrc.toml/.rtxrc in the current directory if `local` is set
+fn rtxrc_for_alias(config: Config, local: bool) -> Result<RTXFile> {
+    if !local {
+        return Ok(config.rtxrc);
+    }
+    let path = [".rtxrc.toml", ".rtxrc"]
+        .iter()
+        .map(|f| dirs::CURRENT.join(f))
+        .find(|p| p.is_file());
+    match path {
+        Some(path) => RTXFile::from_file(&path),
+        None => Ok(RTXFile::init(&dirs::CURRENT.join(".rtxrc.toml"))),
+    }
+}
@@ -0,0 +1,72 @@
+use atty::Stream;
+use color_eyre::eyre::Result;
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::alias::rtxrc_for_alias;
+use crate::cli::command::Command;
+use crate::config::config_file::ConfigFile;
+use crate::config::{is_fully_loaded, Config};
+use crate::file::display_path;
+use crate::output::Output;
+use crate::plugins::PluginName;
+use crate::ui::color::Color;
+
+/// Add/update an alias for a plugin
+///
+/// This modifies the contents of ~/.config/rtx/config.toml
+/// or .rtxrc.toml in the current directory with --local
+/// Aliases in .rtxrc.toml are only used once it is trusted with `rtx trust`.
+#[derive(Debug, clap::Args)]
+#[clap(visible_aliases = ["add", "create"], after_long_help = AFTER_LONG_HELP.as_str(), verbatim_doc_comment)]
+pub struct AliasSet {
+    /// The plugin to set the alias for
+    pub plugin: PluginName,
+    /// The alias to set
+    pub alias: String,
+    /// The value to set the alias to
+    pub value: String,
+
+    /// Modify .rtxrc.toml in the current directory instead of the global config
+    #[clap(short, long)]
+    pub local: bool,
+}
+
+impl Command for AliasSet {
+    fn run(self, config: Config, _out: &mut Output) -> Result<()> {
+        let mut rtxrc = rtxrc_for_alias(config, self.local)?;
+        rtxrc.set_alias(&self.plugin, &self.alias, &self.value);
+        rtxrc.save()?;
+        if self.local && !is_fully_loaded(&rtxrc) {
+            let path = display_path(rtxrc.get_path());
+            warn!("{path} is not trusted so this alias is not used until it is. Trust it with `rtx trust {path}`");
+        }
+        Ok(())
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx alias set nodejs lts/hydrogen 18.0.0
+      $ rtx alias set --local nodejs work 16
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_cli;
+    use crate::test::reset_config;
+
+    #[test]
+    fn test_alias_set() {
+        reset_config();
+
+        assert_cli!("alias", "set", "shfmt", "my/other/alias", "3.1");
+        let stdout = assert_cli!("alias", "get", "shfmt", "my/other/alias");
+        assert_eq!(stdout.trim(), "3.1");
+
+        reset_config();
+    }
+}
@@ -0,0 +1,64 @@
+use atty::Stream;
+use color_eyre::eyre::Result;
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::alias::rtxrc_for_alias;
+use crate::cli::command::Command;
+use crate::config::config_file::ConfigFile;
+use crate::config::Config;
+use crate::output::Output;
+use crate::plugins::PluginName;
+use crate::ui::color::Color;
+
+/// Clears an alias for a plugin
+///
+/// This modifies the contents of ~/.config/rtx/config.toml
+/// or .rtxrc.toml in the current directory with --local
+#[derive(Debug, clap::Args)]
+#[clap(visible_aliases = ["rm", "remove", "delete", "del"], after_long_help = AFTER_LONG_HELP.as_str(), verbatim_doc_comment)]
+pub struct AliasUnset {
+    /// The plugin to remove the alias from
+    pub plugin: PluginName,
+    /// The alias to remove
+    pub alias: String,
+
+    /// Modify .rtxrc.toml in the current directory instead of the global config
+    #[clap(short, long)]
+    pub local: bool,
+}
+
+impl Command for AliasUnset {
+    fn run(self, config: Config, _out: &mut Output) -> Result<()> {
+        let mut rtxrc = rtxrc_for_alias(config, self.local)?;
+        rtxrc.remove_alias(&self.plugin, &self.alias);
+        rtxrc.save()
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx alias unset nodejs lts/hydrogen
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+
+    use crate::test::reset_config;
+    use crate::{assert_cli, assert_cli_err};
+
+    #[test]
+    fn test_alias_unset() {
+        reset_config();
+
+        assert_cli!("alias", "unset", "shfmt", "my/alias");
+        let err = assert_cli_err!("alias", "get", "shfmt", "my/alias");
+        assert_display_snapshot!(err, @"Unknown alias: my/alias");
+
+        reset_config();
+    }
+}
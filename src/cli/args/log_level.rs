@@ -6,7 +6,7 @@ use once_cell::sync::Lazy;
 use crate::env;
 
 #[derive(Clone)]
-pub struct LogLevel(pub LevelFilter);
+pub struct LogLevel;
 
 fn parse_log_level(input: &str) -> core::result::Result<LevelFilter, ParseLevelError> {
     input.parse::<LevelFilter>()
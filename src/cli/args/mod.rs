@@ -1,3 +1,4 @@
 pub mod log_level;
 pub mod runtime;
+pub mod timings;
 pub mod verbose;
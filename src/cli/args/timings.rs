@@ -0,0 +1,19 @@
+use clap::Arg;
+
+#[derive(Clone)]
+pub struct Timings;
+
+impl Timings {
+    /// this is read from the args directly in env::RTX_TIMINGS since config is loaded before
+    /// the args are parsed, it's defined here so clap accepts it
+    pub fn arg() -> clap::Arg {
+        Arg::new("timings")
+            .long("timings")
+            .value_name("FILE")
+            .help("Show how long loading config and running plugin scripts took")
+            .long_help("Show how long loading config and running plugin scripts took\n\nPass a path ending in .json to write them in the Chrome trace format instead, e.g.: --timings=trace.json")
+            .global(true)
+            .num_args(0..=1)
+            .require_equals(true)
+    }
+}
@@ -1,7 +1,7 @@
 use clap::{Arg, ArgAction};
 
 #[derive(Clone)]
-pub struct Verbose(pub u8);
+pub struct Verbose;
 
 impl Verbose {
     pub fn arg() -> clap::Arg {
@@ -1,13 +1,18 @@
-use color_eyre::eyre::Result;
+use std::process::ExitCode;
+
+use color_eyre::eyre::{eyre, Result};
 use itertools::Itertools;
 
 use crate::cli::command::Command;
 use crate::cli::Cli;
-use crate::config::Config;
+use crate::config::{Config, PluginSource};
 use crate::output::Output;
-use crate::runtimes::RuntimeVersion;
+use crate::plugins::Plugin;
 
 /// [internal] simulates asdf for plugins that call "asdf" internally
+///
+/// Commands that scripts commonly parse the output of are translated to match asdf's output.
+/// Everything else is passed through to rtx.
 #[derive(Debug, clap::Args)]
 #[clap(hide = true, verbatim_doc_comment)]
 pub struct Asdf {
@@ -23,7 +28,22 @@ impl Command for Asdf {
 
         match args.get(1).map(|s| s.as_str()) {
             Some("reshim") => Ok(()),
-            Some("list") => list_versions(out, &args),
+            Some("current") => current(&config, out, args.get(2)),
+            Some("where") => r#where(&config, out, &args[2..]),
+            Some("list") if args.get(2).map(|s| s.as_str()) == Some("all") => {
+                list_all(&config, out, &args[3..])
+            }
+            Some("list") => list_versions(&config, out, &args[2..]),
+            Some("latest") if args.get(2).map(|s| s.as_str()) == Some("--all") => {
+                latest_all(&config, out)
+            }
+            Some("plugin-list") => plugin_list(&config, out, &args[2..]),
+            Some("plugin-add") => plugin_add(config, out, &args[2..]),
+            Some("plugin") => match args.get(2).map(|s| s.as_str()) {
+                Some("list") => plugin_list(&config, out, &args[3..]),
+                Some("add") => plugin_add(config, out, &args[3..]),
+                _ => Cli::new().run(config, &args, out),
+            },
             Some("install") => {
                 if args.len() == 4 {
                     let version = args.pop().unwrap();
@@ -36,22 +56,133 @@ impl Command for Asdf {
     }
 }
 
-fn list_versions(out: &mut Output, args: &Vec<String>) -> Result<()> {
-    let mut versions = RuntimeVersion::list()?;
-    let plugin = match args.len() {
-        3 => Some(&args[2]),
-        _ => None,
+/// `asdf current [<name>]`
+/// sets the exit code to 1 if a version is not installed and 126 if no version is set like asdf
+fn current(config: &Config, out: &mut Output, plugin: Option<&String>) -> Result<()> {
+    let plugins = match plugin {
+        Some(plugin) => match config.ts.find_plugin(plugin) {
+            Some(plugin) if plugin.is_installed() => vec![plugin],
+            _ => return Err(eyre!("No such plugin: {plugin}")),
+        },
+        None => config.ts.list_installed_plugins(),
+    };
+    let current_versions = config.ts.list_current_versions_by_plugin();
+    let mut rc: u8 = 0;
+    for plugin in plugins {
+        let versions = current_versions.get(&plugin.name).cloned();
+        let source = config.ts.get_source_for_plugin(&plugin.name);
+        match (versions, source) {
+            (Some(versions), Some(source)) => {
+                let version = versions.iter().map(|rtv| rtv.version.clone()).join(" ");
+                if let Some(rtv) = versions.iter().find(|rtv| !rtv.is_installed()) {
+                    let description = format!(
+                        "Not installed. Run \"asdf install {} {}\"",
+                        plugin.name, rtv.version
+                    );
+                    out.stderr
+                        .writeln(current_line(&plugin.name, &version, &description));
+                    rc = 1;
+                } else {
+                    let description = source_description(&source);
+                    rtxprintln!(
+                        out,
+                        "{}",
+                        current_line(&plugin.name, &version, &description)
+                    );
+                }
+            }
+            _ => {
+                let description = format!(
+                    "No version is set. Run \"asdf <global|shell|local> {} <version>\"",
+                    plugin.name
+                );
+                out.stderr
+                    .writeln(current_line(&plugin.name, "______", &description));
+                rc = 126;
+            }
+        }
+    }
+    out.status = ExitCode::from(rc);
+    Ok(())
+}
+
+fn current_line(plugin: &str, version: &str, description: &str) -> String {
+    format!("{plugin:<15} {version:<15} {description:<10}")
+}
+
+fn source_description(source: &PluginSource) -> String {
+    match source {
+        PluginSource::ToolVersions(path)
+        | PluginSource::RtxRc(path)
+        | PluginSource::LegacyVersionFile(path) => path.display().to_string(),
+        PluginSource::Argument(arg) => arg.to_string(),
+        PluginSource::Environment(k, _) => format!("{k} environment variable"),
+    }
+}
+
+/// `asdf where <name> [<version>]`, unlike `rtx where` the version must match exactly
+fn r#where(config: &Config, out: &mut Output, args: &[String]) -> Result<()> {
+    let plugin = args
+        .first()
+        .ok_or_else(|| eyre!("usage: asdf where <name> [<version>]"))?;
+    let version = match args.get(1) {
+        Some(version) => version.clone(),
+        None => config
+            .ts
+            .list_current_versions_by_plugin()
+            .get(plugin)
+            .and_then(|versions| versions.first().map(|rtv| rtv.version.clone()))
+            .ok_or_else(|| eyre!("Version not installed"))?,
     };
-    if let Some(plugin) = plugin {
-        versions.retain(|v| &v.plugin.name == plugin);
+    if version == "system" {
+        return Err(eyre!("System version is selected"));
+    }
+    let rtv = config
+        .ts
+        .list_installed_versions()
+        .into_iter()
+        .find(|rtv| &rtv.plugin.name == plugin && rtv.version == version)
+        .ok_or_else(|| eyre!("Version not installed"))?;
+    rtxprintln!(out, "{}", rtv.install_path.display());
+    Ok(())
+}
+
+/// `asdf list [<name> [<version>]]`, the current version is marked with "*"
+fn list_versions(config: &Config, out: &mut Output, args: &[String]) -> Result<()> {
+    let current_versions = config.ts.list_current_versions_by_plugin();
+    let query = args.get(1).map(|s| s.as_str()).unwrap_or_default();
+    let print_plugin = |out: &mut Output, plugin: &Plugin| -> Result<()> {
+        let current = current_versions
+            .get(&plugin.name)
+            .map(|versions| versions.iter().map(|rtv| rtv.version.clone()).collect_vec())
+            .unwrap_or_default();
+        let versions = plugin
+            .list_installed_versions()?
+            .into_iter()
+            .filter(|v| v.starts_with(query))
+            .collect_vec();
+        if versions.is_empty() {
+            out.stderr.writeln("  No versions installed".into());
+        }
         for version in versions {
-            rtxprintln!(out, "{}", version.version);
+            let flag = if current.contains(&version) {
+                " *"
+            } else {
+                "  "
+            };
+            rtxprintln!(out, "{}{}", flag, version);
         }
-    } else {
-        for (plugin, versions) in &versions.into_iter().group_by(|v| v.plugin.name.clone()) {
-            rtxprintln!(out, "{}", plugin);
-            for version in versions {
-                rtxprintln!(out, "  {}", version.version);
+        Ok(())
+    };
+    match args.first() {
+        Some(plugin) => match config.ts.find_plugin(plugin) {
+            Some(plugin) if plugin.is_installed() => print_plugin(out, &plugin)?,
+            _ => return Err(eyre!("No such plugin: {plugin}")),
+        },
+        None => {
+            for plugin in config.ts.list_installed_plugins() {
+                rtxprintln!(out, "{}", plugin.name);
+                print_plugin(out, &plugin)?;
             }
         }
     }
@@ -59,12 +190,72 @@ fn list_versions(out: &mut Output, args: &Vec<String>) -> Result<()> {
     Ok(())
 }
 
+/// `asdf list all <name> [<version>]`
+fn list_all(config: &Config, out: &mut Output, args: &[String]) -> Result<()> {
+    let name = args
+        .first()
+        .ok_or_else(|| eyre!("usage: asdf list all <name> [<version>]"))?;
+    let plugin = Plugin::load_ensure_installed(name, &config.settings)?;
+    let query = args.get(1).map(|s| s.as_str()).unwrap_or_default();
+    let versions = plugin
+        .list_remote_versions()?
+        .into_iter()
+        .filter(|v| v.trim_start().starts_with(query))
+        .collect_vec();
+    if versions.is_empty() {
+        return Err(eyre!("No compatible versions available ({name} {query})"));
+    }
+    for version in versions {
+        rtxprintln!(out, "{}", version);
+    }
+    Ok(())
+}
+
+/// `asdf latest --all`, prints "<name>\t<version>\t<installed|missing>" for each plugin
+fn latest_all(config: &Config, out: &mut Output) -> Result<()> {
+    for plugin in config.ts.list_installed_plugins() {
+        let version = plugin
+            .latest_stable_version("latest")?
+            .unwrap_or_else(|| "unknown".into());
+        let status = match plugin.list_installed_versions()?.contains(&version) {
+            true => "installed",
+            false => "missing",
+        };
+        rtxprintln!(out, "{}\t{}\t{}", plugin.name, version, status);
+    }
+    Ok(())
+}
+
+/// `asdf plugin list [--urls]`
+fn plugin_list(config: &Config, out: &mut Output, args: &[String]) -> Result<()> {
+    let urls = args.iter().any(|a| a == "--urls");
+    let plugins = config.ts.list_installed_plugins();
+    if plugins.is_empty() {
+        out.stderr.writeln("No plugins installed".into());
+    }
+    for plugin in plugins.iter().sorted_by_key(|p| &p.name) {
+        match urls.then(|| plugin.get_remote_url()).flatten() {
+            Some(url) => rtxprintln!(out, "{:<28} {}", plugin.name, url),
+            None => rtxprintln!(out, "{}", plugin.name),
+        }
+    }
+    Ok(())
+}
+
+/// `asdf plugin add <name> [<git-url>]`
+fn plugin_add(config: Config, out: &mut Output, args: &[String]) -> Result<()> {
+    let mut rtx_args = vec!["rtx".to_string(), "plugins".into(), "install".into()];
+    rtx_args.extend(args.iter().cloned());
+    Cli::new().run(config, &rtx_args, out)
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_str_eq;
 
     use crate::assert_cli;
     use crate::cli::version::VERSION;
+    use crate::dirs;
 
     #[test]
     fn test_fake_asdf() {
@@ -77,6 +268,50 @@ mod tests {
         assert_cli!("plugin", "install", "shfmt");
         assert_cli!("install", "shfmt@2");
         let stdout = assert_cli!("asdf", "list", "shfmt");
-        assert!(stdout.contains('2'));
+        assert!(stdout.contains("  2"));
+        assert!(stdout.contains(" *3.5.1"));
+    }
+
+    #[test]
+    fn test_fake_asdf_current() {
+        let stdout = assert_cli!("asdf", "current", "shfmt");
+        assert_str_eq!(
+            stdout,
+            format!(
+                "shfmt           3.5.1           {}\n",
+                dirs::CURRENT.join(".tool-versions").display()
+            )
+        );
+    }
+
+    #[test]
+    fn test_fake_asdf_current_no_version() {
+        let args = &vec!["rtx", "asdf", "current", "dummy"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let out = crate::cli::tests::cli_run(args).unwrap();
+        assert_eq!(out.status, std::process::ExitCode::from(126));
+    }
+
+    #[test]
+    fn test_fake_asdf_where() {
+        let stdout = assert_cli!("asdf", "where", "shfmt", "3.5.1");
+        assert_str_eq!(
+            stdout.trim(),
+            dirs::ROOT.join("installs/shfmt/3.5.1").to_string_lossy()
+        );
+    }
+
+    #[test]
+    fn test_fake_asdf_list_all() {
+        let stdout = assert_cli!("asdf", "list", "all", "dummy", "1");
+        assert_str_eq!(stdout, "1.0.0\n1.1.0\n");
+    }
+
+    #[test]
+    fn test_fake_asdf_plugin_list() {
+        let stdout = assert_cli!("asdf", "plugin", "list");
+        assert!(stdout.lines().any(|l| l == "tiny"));
     }
 }
@@ -0,0 +1,58 @@
+use clap::Subcommand;
+use color_eyre::eyre::{eyre, Result};
+
+use crate::binary_cache::BinaryCache;
+use crate::cli::args::runtime::RuntimeArg;
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::output::Output;
+use crate::runtimes::RuntimeVersion;
+
+mod pull;
+mod push;
+
+#[derive(Debug, clap::Args)]
+#[clap(about = "Manage the binary cache of prebuilt runtimes")]
+pub struct Cache {
+    #[clap(subcommand)]
+    command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    Pull(pull::CachePull),
+    Push(push::CachePush),
+}
+
+impl Commands {
+    pub fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        match self {
+            Self::Pull(cmd) => cmd.run(config, out),
+            Self::Push(cmd) => cmd.run(config, out),
+        }
+    }
+}
+
+impl Command for Cache {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        self.command.run(config, out)
+    }
+}
+
+fn binary_cache(config: &Config) -> Result<BinaryCache> {
+    BinaryCache::from_settings(&config.settings).ok_or_else(|| {
+        eyre!("binary_cache is not set, set it with `rtx settings set binary_cache <DIR|URL>` or RTX_BINARY_CACHE")
+    })
+}
+
+/// the runtime versions the arguments resolve to
+fn runtime_versions(config: &mut Config, runtimes: &[RuntimeArg]) -> Result<Vec<RuntimeVersion>> {
+    let mut versions = vec![];
+    for r in runtimes {
+        if let Some(version) = config.resolve_runtime_arg(r)? {
+            let plugin = config.ts.get_or_add_plugin(r.plugin.clone())?;
+            versions.push(RuntimeVersion::new(plugin, &version));
+        }
+    }
+    Ok(versions)
+}
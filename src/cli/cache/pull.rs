@@ -0,0 +1,77 @@
+use atty::Stream;
+use color_eyre::eyre::Result;
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::args::runtime::{RuntimeArg, RuntimeArgParser};
+use crate::cli::cache::{binary_cache, runtime_versions};
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::output::Output;
+use crate::ui::color::Color;
+
+/// installs runtimes from the binary cache without building them
+///
+/// Only runtimes which were pushed with the same plugin git sha, os, arch and install path
+/// are used. The tarball is verified with its sha256 before it is unpacked.
+/// `rtx install` also checks the binary cache first so this is mostly useful to warm up a
+/// machine without falling back to building runtimes which are not cached.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct CachePull {
+    /// runtime(s) to download, defaults to the missing runtimes for the current directory
+    ///
+    /// e.g.: python@3.11.1
+    #[clap(value_parser = RuntimeArgParser)]
+    runtime: Vec<RuntimeArg>,
+}
+
+impl Command for CachePull {
+    fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        let cache = binary_cache(&config)?;
+        let versions = match self.runtime.is_empty() {
+            true => config
+                .ts
+                .list_current_versions()
+                .into_iter()
+                .filter(|rtv| rtv.version != "system" && !rtv.is_installed())
+                .map(|rtv| (*rtv).clone())
+                .collect(),
+            false => runtime_versions(&mut config, &self.runtime)?,
+        };
+        for rtv in versions {
+            if rtv.is_installed() {
+                warn!("{rtv} is already installed");
+            } else if rtv.pull_from_binary_cache(&cache, &config)? {
+                rtxprintln!(out, "pulled {rtv}");
+            } else {
+                warn!("{rtv} is not in the binary cache");
+            }
+        }
+        Ok(())
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ export RTX_BINARY_CACHE=https://rtx-cache.example.com
+      $ rtx cache pull python@3.11.1
+      pulled python@3.11.1
+
+      # pull every missing runtime for the current directory
+      $ rtx cache pull
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_cli_err;
+
+    #[test]
+    fn test_cache_pull_without_cache() {
+        let err = assert_cli_err!("cache", "pull");
+        assert!(err.to_string().starts_with("binary_cache is not set"));
+    }
+}
@@ -0,0 +1,75 @@
+use atty::Stream;
+use color_eyre::eyre::Result;
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::args::runtime::{RuntimeArg, RuntimeArgParser};
+use crate::cli::cache::{binary_cache, runtime_versions};
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::output::Output;
+use crate::ui::color::Color;
+
+/// uploads installed runtimes to the binary cache
+///
+/// The install directory is stored as a tarball along with the plugin's git sha, os and arch.
+/// `rtx install` will unpack it instead of building the runtime on other machines with the same
+/// plugin sha, os, arch and install path.
+///
+/// The cache is set with `binary_cache` in ~/.config/rtx/config.toml or RTX_BINARY_CACHE.
+/// It can be a directory (e.g.: on NFS) or an http(s) url which accepts PUT requests.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct CachePush {
+    /// runtime(s) to upload, defaults to the installed runtimes for the current directory
+    ///
+    /// e.g.: python@3.11.1
+    #[clap(value_parser = RuntimeArgParser)]
+    runtime: Vec<RuntimeArg>,
+}
+
+impl Command for CachePush {
+    fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        let cache = binary_cache(&config)?;
+        let versions = match self.runtime.is_empty() {
+            true => config
+                .ts
+                .list_current_installed_versions()
+                .into_iter()
+                .filter(|rtv| rtv.version != "system")
+                .map(|rtv| (*rtv).clone())
+                .collect(),
+            false => runtime_versions(&mut config, &self.runtime)?,
+        };
+        for rtv in versions {
+            let metadata = cache.push(&rtv)?;
+            rtxprintln!(out, "pushed {rtv} ({})", metadata.sha256);
+        }
+        Ok(())
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ export RTX_BINARY_CACHE=/mnt/rtx-cache
+      $ rtx install python@3.11.1
+      $ rtx cache push python@3.11.1
+      pushed python@3.11.1 (3e2a9c...)
+
+      # push every installed runtime used in the current directory
+      $ rtx cache push
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_cli_err;
+
+    #[test]
+    fn test_cache_push_without_cache() {
+        let err = assert_cli_err!("cache", "push");
+        assert!(err.to_string().starts_with("binary_cache is not set"));
+    }
+}
@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use atty::Stream;
+use color_eyre::eyre::{eyre, Result};
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+use toml_edit::{Item, Value};
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::file::display_path;
+use crate::output::Output;
+use crate::ui::color::Color;
+
+/// prints the value of a key in a config file
+///
+/// Keys are dotted paths into the file like `env.NODE_ENV` or `alias.nodejs.lts`.
+/// Strings are printed as-is, other values and tables as TOML.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct ConfigGet {
+    /// the key to print, e.g.: env.NODE_ENV
+    key: String,
+
+    /// the config file to read, defaults to ~/.config/rtx/config.toml
+    #[clap(short, long, value_hint = clap::ValueHint::FilePath)]
+    file: Option<PathBuf>,
+}
+
+impl Command for ConfigGet {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let rtxrc = super::config_file(self.file, &config)?;
+        match rtxrc.get_setting(&self.key)? {
+            Some(Item::Value(Value::String(s))) => rtxprintln!(out, "{}", s.value()),
+            Some(Item::Value(mut v)) => {
+                v.decor_mut().clear();
+                rtxprintln!(out, "{}", v);
+            }
+            Some(Item::None) | None => {
+                return Err(eyre!(
+                    "{} is not set in {}",
+                    self.key,
+                    display_path(&rtxrc.path)
+                ))
+            }
+            Some(item) => rtxprint!(out, "{}", item),
+        }
+        Ok(())
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx config get legacy_version_file
+      true
+
+      $ rtx config get --file .rtxrc.toml env.NODE_ENV
+      production
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+
+    use crate::{assert_cli, assert_cli_err};
+
+    #[test]
+    fn test_config_get() {
+        let stdout = assert_cli!("config", "get", "missing_runtime_behavior");
+        assert_display_snapshot!(stdout, @"autoinstall");
+        let stdout = assert_cli!("config", "get", "plugin_autoupdate_last_check_duration");
+        assert_display_snapshot!(stdout, @"20");
+        let stdout = assert_cli!("config", "get", "alias.shfmt");
+        assert_display_snapshot!(stdout, @r###""my/alias" = '3.0'"###);
+        let err = assert_cli_err!("config", "get", "env.FOO");
+        assert_display_snapshot!(err, @"env.FOO is not set in ~/config/config.toml");
+    }
+}
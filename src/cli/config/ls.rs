@@ -0,0 +1,245 @@
+use std::path::{Path, PathBuf};
+
+use atty::Stream;
+use color_eyre::eyre::Result;
+use indexmap::IndexMap;
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+use serde_derive::Serialize;
+
+use crate::cli::command::Command;
+use crate::config::config_file::{detect_config_file_type, ConfigFile};
+use crate::config::{is_fully_loaded, parse_config_file, Config, PluginSource};
+use crate::file::display_path;
+use crate::output::Output;
+use crate::ui::color::{dimmed, red, Color};
+
+/// lists the config files rtx found and what each of them contributes
+///
+/// Files are listed from the highest precedence to the lowest, the closest project
+/// config files first and ~/.config/rtx/config.toml last. For each file this shows
+/// the runtime versions it sets and whether those were overridden by a closer file
+/// or an RTX_*_VERSION env var, as well as the env vars, aliases and settings it sets.
+///
+/// Untrusted files only contribute their runtime versions, see `rtx trust`.
+#[derive(Debug, clap::Args)]
+#[clap(visible_alias = "list", verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct ConfigLs {
+    /// output in JSON format
+    #[clap(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ConfigFileInfo {
+    path: PathBuf,
+    #[serde(rename = "type")]
+    config_type: Option<String>,
+    /// the error if the file could not be parsed
+    error: Option<String>,
+    trusted: bool,
+    plugins: Vec<PluginInfo>,
+    env: IndexMap<String, String>,
+    env_remove: Vec<String>,
+    aliases: IndexMap<String, IndexMap<String, String>>,
+    settings: IndexMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+struct PluginInfo {
+    name: String,
+    versions: Vec<String>,
+    /// where the versions for this plugin come from instead, if not from this file
+    overridden_by: Option<String>,
+}
+
+impl Command for ConfigLs {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let mut files = config
+            .config_files
+            .iter()
+            .map(|path| {
+                let cf = parse_config_file(path, &config.legacy_filenames, &config.ts);
+                match cf {
+                    Ok(cf) => file_info(&config, cf.as_ref(), is_fully_loaded(cf.as_ref())),
+                    Err(err) => ConfigFileInfo {
+                        config_type: detect_config_file_type(path).map(|t| t.to_string()),
+                        error: Some(format!("{err:#}")),
+                        ..empty_file_info(path)
+                    },
+                }
+            })
+            .collect::<Vec<_>>();
+        if config.rtxrc.path.exists() {
+            let mut global = file_info(&config, &config.rtxrc, true);
+            global.settings = config.rtxrc.settings_builder().to_index_map();
+            files.push(global);
+        }
+
+        if self.json {
+            rtxprintln!(out, "{}", serde_json::to_string_pretty(&files)?);
+            return Ok(());
+        }
+        for file in files {
+            render_file(out, &file);
+        }
+        Ok(())
+    }
+}
+
+fn empty_file_info(path: &Path) -> ConfigFileInfo {
+    ConfigFileInfo {
+        path: path.to_path_buf(),
+        config_type: None,
+        error: None,
+        trusted: false,
+        plugins: vec![],
+        env: IndexMap::new(),
+        env_remove: vec![],
+        aliases: IndexMap::new(),
+        settings: IndexMap::new(),
+    }
+}
+
+fn file_info(config: &Config, cf: &dyn ConfigFile, trusted: bool) -> ConfigFileInfo {
+    let path = cf.get_path();
+    let plugins = cf
+        .plugins()
+        .into_iter()
+        .map(|(name, versions)| {
+            let overridden_by = match config.ts.get_source_for_plugin(&name) {
+                Some(source) if source_path(&source) == Some(path) => None,
+                Some(source) => Some(source.to_string()),
+                None => Some("not used".into()),
+            };
+            PluginInfo {
+                name,
+                versions,
+                overridden_by,
+            }
+        })
+        .collect();
+    let mut info = ConfigFileInfo {
+        config_type: Some(cf.get_type().to_string()),
+        trusted,
+        plugins,
+        ..empty_file_info(path)
+    };
+    if info.trusted {
+        info.env = cf.env().into_iter().collect();
+        info.env.sort_keys();
+        info.env_remove = cf.env_remove();
+        info.aliases = cf.aliases();
+    }
+    info
+}
+
+fn source_path(source: &PluginSource) -> Option<&Path> {
+    match source {
+        PluginSource::ToolVersions(path)
+        | PluginSource::RtxRc(path)
+        | PluginSource::LegacyVersionFile(path) => Some(path),
+        PluginSource::Argument(_) | PluginSource::Environment(_, _) => None,
+    }
+}
+
+fn render_file(out: &mut Output, file: &ConfigFileInfo) {
+    let config_type = file.config_type.as_deref().unwrap_or("unknown");
+    let mut header = format!("{} ({config_type})", display_path(&file.path));
+    if let Some(err) = &file.error {
+        rtxprintln!(out, "{header}");
+        for line in format!("error: {}", err.trim_end()).lines() {
+            rtxprintln!(out, "  {}", red(Stream::Stdout, line));
+        }
+        return;
+    }
+    if !file.trusted {
+        header.push_str(" (untrusted, only the runtime versions are used)");
+    }
+    rtxprintln!(out, "{header}");
+    for plugin in &file.plugins {
+        let line = format!("  {} {}", plugin.name, plugin.versions.join(" "));
+        match &plugin.overridden_by {
+            Some(source) => {
+                let line = format!("{line} (overridden by {source})");
+                rtxprintln!(out, "{}", dimmed(Stream::Stdout, &line));
+            }
+            None => rtxprintln!(out, "{line}"),
+        }
+    }
+    for (k, v) in &file.env {
+        rtxprintln!(out, "  env {k}={v}");
+    }
+    for k in &file.env_remove {
+        rtxprintln!(out, "  env {k} (unset)");
+    }
+    for (plugin, aliases) in &file.aliases {
+        for (from, to) in aliases {
+            rtxprintln!(out, "  alias {plugin} {from} -> {to}");
+        }
+    }
+    for (k, v) in &file.settings {
+        rtxprintln!(out, "  setting {k} = {v}");
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx config ls
+      ~/src/myproj/app/.tool-versions (tool-versions)
+        nodejs 18
+      ~/src/myproj/.rtxrc.toml (rtxrc)
+        nodejs 16 (overridden by ~/src/myproj/app/.tool-versions)
+        python 3.11
+        env NODE_ENV=production
+      ~/.config/rtx/config.toml (rtxrc)
+        alias nodejs my_custom_node -> 18
+        setting verbose = true
+
+      $ rtx config ls --json
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+
+    use crate::assert_cli;
+
+    #[test]
+    fn test_config_ls() {
+        let stdout = assert_cli!("config", "ls");
+        assert_display_snapshot!(stdout, @r###"
+        ~/cwd/.tool-versions (tool-versions)
+          shellcheck 0.9.0
+          shfmt 3.5.1
+          nodejs system
+        ~/.tool-versions (tool-versions)
+          shfmt 2 (overridden by ~/cwd/.tool-versions)
+          jq 1.6
+          tiny 2
+        ~/config/config.toml (rtxrc)
+          alias shfmt my/alias -> 3.0
+          setting missing_runtime_behavior = autoinstall
+          setting always_keep_download = true
+          setting legacy_version_file = true
+          setting plugin_autoupdate_last_check_duration = 20
+          setting verbose = true
+        "###);
+    }
+
+    #[test]
+    fn test_config_ls_json() {
+        let stdout = assert_cli!("config", "ls", "--json");
+        let files: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        let home = &files[1];
+        assert_eq!(home["type"], "tool-versions");
+        assert_eq!(home["error"], serde_json::Value::Null);
+        assert_eq!(home["plugins"][0]["name"], "shfmt");
+        assert_eq!(home["plugins"][0]["overridden_by"], "~/cwd/.tool-versions");
+        assert_eq!(home["plugins"][2]["overridden_by"], serde_json::Value::Null);
+        assert_eq!(files[2]["aliases"]["shfmt"]["my/alias"], "3.0");
+    }
+}
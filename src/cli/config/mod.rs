@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+use color_eyre::eyre::{eyre, Result};
+
+use crate::cli::command::Command;
+use crate::config::config_file::rtxrc::RTXFile;
+use crate::config::config_file::{detect_config_file_type, ConfigFileType};
+use crate::config::Config;
+use crate::file::display_path;
+use crate::output::Output;
+
+mod get;
+mod ls;
+mod schema;
+mod set;
+mod unset;
+mod validate;
+
+#[derive(Debug, clap::Args)]
+#[clap(about = "Manage config files")]
+pub struct ConfigCmd {
+    #[clap(subcommand)]
+    command: Option<Commands>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    Get(get::ConfigGet),
+    Ls(ls::ConfigLs),
+    Schema(schema::ConfigSchema),
+    Set(set::ConfigSet),
+    Unset(unset::ConfigUnset),
+    Validate(validate::ConfigValidate),
+}
+
+impl Commands {
+    pub fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        match self {
+            Self::Get(cmd) => cmd.run(config, out),
+            Self::Ls(cmd) => cmd.run(config, out),
+            Self::Schema(cmd) => cmd.run(config, out),
+            Self::Set(cmd) => cmd.run(config, out),
+            Self::Unset(cmd) => cmd.run(config, out),
+            Self::Validate(cmd) => cmd.run(config, out),
+        }
+    }
+}
+
+impl Command for ConfigCmd {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let cmd = self
+            .command
+            .unwrap_or(Commands::Ls(ls::ConfigLs { json: false }));
+
+        cmd.run(config, out)
+    }
+}
+
+/// the config file for `--file`, which defaults to ~/.config/rtx/config.toml
+fn config_file(file: Option<PathBuf>, config: &Config) -> Result<RTXFile> {
+    let path = file.unwrap_or_else(|| config.rtxrc.path.clone());
+    if detect_config_file_type(&path) != Some(ConfigFileType::RtxRc) {
+        return Err(eyre!("{} is not an rtx config file", display_path(&path)));
+    }
+    Ok(RTXFile::init(&path))
+}
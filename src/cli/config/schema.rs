@@ -0,0 +1,35 @@
+use atty::Stream;
+use color_eyre::eyre::Result;
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::{schema, Config};
+use crate::output::Output;
+use crate::ui::color::Color;
+
+/// prints the JSON Schema for config.toml and project config files
+///
+/// Editors can use this for completion and to show problems in config files, for
+/// example with the Even Better TOML extension for VS Code.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct ConfigSchema {}
+
+impl Command for ConfigSchema {
+    fn run(self, _config: Config, out: &mut Output) -> Result<()> {
+        rtxprint!(out, "{}", schema::SCHEMA);
+        Ok(())
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx config schema > rtx.schema.json
+
+      # then at the top of .rtxrc.toml:
+      #:schema ./rtx.schema.json
+    "#, COLOR.header("Examples:")}
+});
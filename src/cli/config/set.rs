@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use atty::Stream;
+use color_eyre::eyre::{eyre, Result, WrapErr};
+use indoc::formatdoc;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::config_file::rtxrc::RTXFile;
+use crate::config::config_file::ConfigFile;
+use crate::config::{schema, Config};
+use crate::errors::Error::InvalidConfig;
+use crate::output::Output;
+use crate::ui::color::Color;
+
+/// sets the value of a key in a config file
+///
+/// Keys are dotted paths into the file like `env.NODE_ENV` or `alias.nodejs.lts`,
+/// tables are created as needed. The value is read as TOML so it can be a boolean,
+/// integer, array or inline table, e.g.: true, 10, ["18", "16"], { run = "npm test" }.
+/// Anything else, or a value where the key expects a string, is stored as a string.
+///
+/// The change is checked against the schema used by `rtx config validate` so unknown
+/// settings and values of the wrong type are rejected. The formatting and comments in
+/// the rest of the file are kept.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct ConfigSet {
+    /// the key to set, e.g.: env.NODE_ENV
+    key: String,
+
+    /// the value to set
+    value: String,
+
+    /// the config file to modify, defaults to ~/.config/rtx/config.toml
+    #[clap(short, long, value_hint = clap::ValueHint::FilePath)]
+    file: Option<PathBuf>,
+}
+
+impl Command for ConfigSet {
+    fn run(self, config: Config, _out: &mut Output) -> Result<()> {
+        let rtxrc = super::config_file(self.file, &config)?;
+        let existing = problems(&rtxrc)?;
+        let mut errors = None;
+        for value in candidates(&self.value) {
+            let mut rtxrc = RTXFile::init(&rtxrc.path);
+            rtxrc.update_setting(&self.key, value)?;
+            let new_problems = problems(&rtxrc)?
+                .into_iter()
+                .filter(|p| !existing.contains(p))
+                .collect_vec();
+            if new_problems.is_empty() {
+                return rtxrc.save();
+            }
+            errors.get_or_insert(new_problems);
+        }
+        Err(eyre!(errors.unwrap_or_default().join("\n")))
+    }
+}
+
+/// the value read as TOML, followed by the value as a string
+fn candidates(value: &str) -> Vec<toml_edit::Value> {
+    match value.parse::<toml_edit::Value>() {
+        Ok(v) if !v.is_str() => vec![v, value.into()],
+        _ => vec![value.into()],
+    }
+}
+
+/// fails if the file isn't valid TOML rather than editing it
+fn problems(rtxrc: &RTXFile) -> Result<HashSet<String>> {
+    let doc = rtxrc
+        .get_edit()
+        .wrap_err_with(|| InvalidConfig(rtxrc.path.clone()))?;
+    Ok(schema::validate(&doc.to_string())
+        .into_iter()
+        .map(|p| p.message)
+        .collect())
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx config set legacy_version_file false
+      $ rtx config set alias.nodejs.lts 18
+      $ rtx config set --file .rtxrc.toml nodejs '["18", "16"]'
+      $ rtx config set --file .rtxrc.toml env.NODE_ENV production
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use insta::assert_display_snapshot;
+
+    use crate::errors::Error;
+    use crate::test::reset_config;
+    use crate::{assert_cli, assert_cli_err};
+
+    #[test]
+    fn test_config_set() {
+        reset_config();
+        assert_cli!("config", "set", "legacy_version_file", "false");
+        assert_cli!("config", "set", "alias.tiny.lts", "2");
+        assert_cli!("config", "set", "env_path", r#"["./bin"]"#);
+        let stdout = assert_cli!("config", "get", "legacy_version_file");
+        assert_display_snapshot!(stdout, @"false");
+        let stdout = assert_cli!("config", "get", "alias.tiny.lts");
+        assert_display_snapshot!(stdout, @"2");
+        let stdout = assert_cli!("config", "get", "env_path");
+        assert_display_snapshot!(stdout, @r###"["./bin"]"###);
+        reset_config();
+    }
+
+    #[test]
+    fn test_config_set_invalid() {
+        let err = assert_cli_err!("config", "set", "verbose", "yes");
+        assert_display_snapshot!(err, @"expected verbose to be a boolean, got: string");
+        let err = assert_cli_err!("config", "set", "legacy_version_files", "true");
+        assert_display_snapshot!(err, @"unknown key: legacy_version_files. Did you mean legacy_version_file?");
+    }
+
+    #[test]
+    fn test_config_set_unparseable() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".rtxrc.toml");
+        fs::write(&path, "nodejs = [\n").unwrap();
+        let err = assert_cli_err!(
+            "config",
+            "set",
+            "--file",
+            path.to_str().unwrap(),
+            "verbose",
+            "1"
+        );
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::InvalidConfig(_))
+        ));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "nodejs = [\n");
+    }
+}
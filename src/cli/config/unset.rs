@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+
+use atty::Stream;
+use color_eyre::eyre::Result;
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::config_file::ConfigFile;
+use crate::config::Config;
+use crate::output::Output;
+use crate::ui::color::Color;
+
+/// removes a key from a config file
+///
+/// Keys are dotted paths into the file like `env.NODE_ENV` or `alias.nodejs.lts`.
+/// Nothing is changed if the key is not set.
+#[derive(Debug, clap::Args)]
+#[clap(visible_aliases = ["rm", "remove"], verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct ConfigUnset {
+    /// the key to remove, e.g.: env.NODE_ENV
+    key: String,
+
+    /// the config file to modify, defaults to ~/.config/rtx/config.toml
+    #[clap(short, long, value_hint = clap::ValueHint::FilePath)]
+    file: Option<PathBuf>,
+}
+
+impl Command for ConfigUnset {
+    fn run(self, config: Config, _out: &mut Output) -> Result<()> {
+        let mut rtxrc = super::config_file(self.file, &config)?;
+        rtxrc.remove_setting(&self.key)?;
+        rtxrc.save()
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx config unset legacy_version_file
+      $ rtx config unset --file .rtxrc.toml env.NODE_ENV
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+
+    use crate::test::reset_config;
+    use crate::{assert_cli, assert_cli_err};
+
+    #[test]
+    fn test_config_unset() {
+        reset_config();
+        assert_cli!("config", "unset", "alias.shfmt.\"my/alias\"");
+        let err = assert_cli_err!("config", "get", "alias.shfmt.\"my/alias\"");
+        assert_display_snapshot!(err, @r###"alias.shfmt."my/alias" is not set in ~/config/config.toml"###);
+        reset_config();
+    }
+}
@@ -0,0 +1,120 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use atty::Stream;
+use color_eyre::eyre::{eyre, Result};
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::config_file::{detect_config_file_type, ConfigFileType};
+use crate::config::{schema, Config};
+use crate::file::display_path;
+use crate::output::Output;
+use crate::ui::color::Color;
+
+/// checks config files for unknown keys and values of the wrong type
+///
+/// Any top-level key which is not a setting is treated as a plugin, so a misspelled
+/// setting is reported unless a plugin with that name is installed or in the
+/// shorthand repository. Problems are shown as <file>:<line>:<column>: <message>
+/// and rtx exits with 1 if there are any.
+///
+/// Set RTX_STRICT=1 to have rtx fail to load config files with these problems.
+///
+/// The JSON Schema these are checked against is in schema/rtx.json in the rtx
+/// repository. Editors can use it for completion.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct ConfigValidate {
+    /// the config file to check
+    /// defaults to ~/.config/rtx/config.toml and the project config files in use
+    #[clap(value_hint = clap::ValueHint::FilePath, verbatim_doc_comment)]
+    file: Option<PathBuf>,
+}
+
+impl Command for ConfigValidate {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let files = match self.file {
+            Some(file) => match detect_config_file_type(&file) {
+                Some(ConfigFileType::RtxRc) => vec![file],
+                _ => return Err(eyre!("{} is not an rtx config file", display_path(&file))),
+            },
+            None => {
+                let project_files = config
+                    .config_files
+                    .iter()
+                    .filter(|p| detect_config_file_type(p) == Some(ConfigFileType::RtxRc));
+                std::iter::once(&config.rtxrc.path)
+                    .filter(|p| p.exists())
+                    .chain(project_files)
+                    .cloned()
+                    .collect()
+            }
+        };
+        let mut invalid = false;
+        for file in &files {
+            let body =
+                fs::read_to_string(file).map_err(|err| eyre!("{}: {err}", display_path(file)))?;
+            let problems = schema::validate(&body);
+            if problems.is_empty() {
+                rtxprintln!(out, "{}: valid", display_path(file));
+            }
+            for problem in problems {
+                invalid = true;
+                rtxprintln!(out, "{}:{problem}", display_path(file));
+            }
+        }
+        if invalid {
+            out.status = ExitCode::from(1);
+        }
+        Ok(())
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx config validate
+      ~/.config/rtx/config.toml: valid
+      ~/src/myproj/.rtxrc.toml:3:1: unknown key: legacy_version_files. Did you mean legacy_version_file?
+
+      $ rtx config validate .rtx.local.toml
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+
+    use crate::assert_cli;
+    use crate::cli::tests::cli_run;
+
+    use super::*;
+
+    #[test]
+    fn test_config_validate() {
+        let stdout = assert_cli!("config", "validate");
+        assert_display_snapshot!(stdout, @r###"
+        ~/config/config.toml: valid
+        "###);
+    }
+
+    #[test]
+    fn test_config_validate_invalid() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join(".rtxrc.toml");
+        fs::write(&file, "verbose = \"yes\"\n").unwrap();
+        let args = ["rtx", "config", "validate", file.to_str().unwrap()]
+            .map(String::from)
+            .to_vec();
+        let out = cli_run(&args).unwrap();
+        assert!(out
+            .stdout
+            .content
+            .ends_with(":1:1: expected verbose to be a boolean, got: string\n"));
+        assert_eq!(out.status, ExitCode::from(1));
+    }
+}
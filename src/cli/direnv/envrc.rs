@@ -35,7 +35,7 @@ impl Command for Envrc {
             file,
             "### Do not edit. This was autogenerated by 'asdf direnv envrc' ###"
         )?;
-        for cf in &config.config_files {
+        for cf in config.config_files.iter().chain(config.env_files.iter()) {
             writeln!(file, "watch_file {}", cf.to_string_lossy())?;
         }
         for (k, v) in config.env()? {
@@ -46,7 +46,11 @@ impl Command for Envrc {
                 shell_escape::unix::escape(v.into()),
             )?;
         }
-        for path in &config.list_paths()? {
+        for k in &config.env_remove {
+            writeln!(file, "unset {}", shell_escape::unix::escape(k.into()))?;
+        }
+        // PATH_add prepends so add them in reverse to keep the order of list_paths()
+        for path in config.list_paths()?.iter().rev() {
             writeln!(file, "PATH_add {}", path.to_string_lossy())?;
         }
 
@@ -35,6 +35,9 @@ impl Command for DirenvExec {
             cmd = cmd.env(k, v);
         }
         cmd = cmd.env("PATH", config.path_env()?);
+        for k in &config.env_remove {
+            cmd = cmd.env_remove(k);
+        }
 
         let json = cmd!("direnv", "watch", "json", ".tool-versions").read()?;
         let w: DirenvWatches = serde_json::from_str(&json)?;
@@ -1,11 +1,20 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+
 use atty::Stream;
-use color_eyre::eyre::Result;
+use clap::ValueEnum;
+use color_eyre::eyre::{eyre, Result};
+use indexmap::IndexMap;
 use indoc::formatdoc;
+use itertools::Itertools;
 use once_cell::sync::Lazy;
 
 use crate::cli::args::runtime::{RuntimeArg, RuntimeArgParser};
 use crate::cli::command::Command;
 use crate::config::Config;
+use crate::env;
+use crate::env_diff::EnvDiffOperation;
+use crate::file::display_path;
 use crate::output::Output;
 use crate::shell::{get_shell, ShellType};
 use crate::ui::color::Color;
@@ -17,13 +26,30 @@ use crate::ui::color::Color;
 /// This can be used similarly to `asdf shell`.
 /// Unfortunately, it requires `eval` to work since it's not written in Bash though.
 /// It's also useful just to see what environment variables rtx sets.
+///
+/// Use --format to output the environment for other tools such as CI systems and containers.
+/// Use --explain to see where each env var and PATH entry comes from.
 #[derive(Debug, clap::Args)]
 #[clap(visible_alias = "e", verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
 pub struct Env {
     /// Shell type to generate environment variables for
-    #[clap(long, short)]
+    #[clap(long, short, conflicts_with = "format")]
     shell: Option<ShellType>,
 
+    /// Output format to use instead of shell commands
+    ///
+    /// github-actions appends to the files at $GITHUB_ENV and $GITHUB_PATH
+    /// nul outputs KEY=VALUE entries separated by NUL bytes
+    /// none of these can unset vars so vars removed with `VAR = false` in [env] are left as is
+    #[clap(long, short, verbatim_doc_comment)]
+    format: Option<EnvFormat>,
+
+    /// Show where each env var and PATH entry comes from instead
+    ///
+    /// Use --explain=VAR to only show VAR, this also works for vars rtx does not set
+    #[clap(long, value_name = "VAR", num_args = 0..=1, require_equals = true, default_missing_value = "", conflicts_with_all = ["shell", "format"], verbatim_doc_comment)]
+    explain: Option<String>,
+
     /// runtime version to use
     #[clap(value_parser = RuntimeArgParser)]
     runtime: Vec<RuntimeArg>,
@@ -34,12 +60,51 @@ impl Command for Env {
         let config = config.with_runtime_args(&self.runtime)?;
         config.ensure_installed()?;
 
+        if let Some(var) = &self.explain {
+            let var = Some(var.as_str()).filter(|v| !v.is_empty());
+            return explain(&config, var, out);
+        }
+        if let Some(format) = self.format {
+            if !config.env_remove.is_empty() {
+                let name = format.to_possible_value().unwrap();
+                warn!(
+                    "--format={} cannot unset env vars, these are not removed: {}",
+                    name.get_name(),
+                    config.env_remove.join(", ")
+                );
+            }
+        }
+        match self.format {
+            Some(EnvFormat::Dotenv) => self.output_dotenv(&config, out),
+            Some(EnvFormat::Json) => self.output_json(&config, out),
+            Some(EnvFormat::GithubActions) => self.output_github_actions(&config),
+            Some(EnvFormat::Dockerfile) => self.output_dockerfile(&config, out),
+            Some(EnvFormat::Nul) => self.output_nul(&config, out),
+            None => self.output_shell(&config, out),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum EnvFormat {
+    Dotenv,
+    Json,
+    GithubActions,
+    Dockerfile,
+    Nul,
+}
+
+impl Env {
+    fn output_shell(&self, config: &Config, out: &mut Output) -> Result<()> {
         let shell = get_shell(self.shell);
         for (k, v) in config.env()? {
             let k = k.to_string();
             let v = v.to_string();
             rtxprint!(out, "{}", shell.set_env(&k, &v));
         }
+        for k in &config.env_remove {
+            rtxprint!(out, "{}", shell.unset_env(k));
+        }
         rtxprintln!(
             out,
             "{}",
@@ -48,6 +113,282 @@ impl Command for Env {
 
         Ok(())
     }
+
+    fn output_dotenv(&self, config: &Config, out: &mut Output) -> Result<()> {
+        for (k, v) in env_with_path(config)? {
+            rtxprintln!(out, "{}={}", k, double_quote(&v));
+        }
+        Ok(())
+    }
+
+    fn output_json(&self, config: &Config, out: &mut Output) -> Result<()> {
+        let env = env_with_path(config)?
+            .into_iter()
+            .map(|(k, v)| (k, serde_json::Value::String(v)))
+            .collect::<serde_json::Map<_, _>>();
+        rtxprintln!(out, "{}", serde_json::to_string_pretty(&env)?);
+        Ok(())
+    }
+
+    fn output_github_actions(&self, config: &Config) -> Result<()> {
+        let github_env = env::var("GITHUB_ENV").map_err(|_| eyre!("GITHUB_ENV is not set"))?;
+        let github_path = env::var("GITHUB_PATH").map_err(|_| eyre!("GITHUB_PATH is not set"))?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(github_env)?;
+        for (k, v) in config.env()? {
+            match v.contains('\n') {
+                true => {
+                    let mut delimiter = String::from("RTX_EOF");
+                    while v.contains(&delimiter) {
+                        delimiter.push('_');
+                    }
+                    writeln!(file, "{k}<<{delimiter}\n{v}\n{delimiter}")?;
+                }
+                false => writeln!(file, "{k}={v}")?,
+            }
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(github_path)?;
+        // each line is prepended to PATH so write them in reverse to keep the order
+        for path in config.list_paths()?.iter().rev() {
+            writeln!(file, "{}", path.display())?;
+        }
+        Ok(())
+    }
+
+    fn output_dockerfile(&self, config: &Config, out: &mut Output) -> Result<()> {
+        for (k, v) in config.env()? {
+            rtxprintln!(out, "ENV {}={}", k, double_quote(&v));
+        }
+        let paths = config.list_paths()?;
+        if !paths.is_empty() {
+            // keep the PATH of the image rather than the PATH of this machine
+            let paths = env::join_paths(paths)?.to_string_lossy().to_string();
+            rtxprintln!(out, "ENV PATH=\"{}:$PATH\"", escape(&paths));
+        }
+        Ok(())
+    }
+
+    fn output_nul(&self, config: &Config, out: &mut Output) -> Result<()> {
+        for (k, v) in env_with_path(config)? {
+            rtxprint!(out, "{}={}\0", k, v);
+        }
+        Ok(())
+    }
+}
+
+/// shows the value of each var rtx sets (or just `var`) and where it came from, the PATH
+/// entries rtx adds and what __RTX_DIFF restores when leaving the directory
+fn explain(config: &Config, var: Option<&str>, out: &mut Output) -> Result<()> {
+    let env = config.env()?;
+    let runtime_envs = config
+        .ts
+        .list_current_installed_versions()
+        .into_iter()
+        .map(|rtv| Ok((rtv.to_string(), rtv.exec_env()?)))
+        .collect::<Result<Vec<_>>>()?;
+    let keys = match var {
+        Some(var) => vec![var.to_string()],
+        None => env
+            .keys()
+            .chain(config.env_remove.iter())
+            .cloned()
+            .sorted()
+            .collect(),
+    };
+    for key in keys.iter().filter(|k| *k != "PATH") {
+        let runtimes = runtime_envs
+            .iter()
+            .filter_map(|(rtv, env)| env.get(key).map(|v| (rtv, v)))
+            .collect_vec();
+        let pristine = env::PRISTINE_ENV.get(key);
+        match env.get(key) {
+            Some(value) => {
+                rtxprintln!(out, "{key}={value}");
+                let source = config
+                    .env_sources
+                    .get(key)
+                    .filter(|_| config.env.contains_key(key));
+                let mut set = false;
+                if let Some(source) = source {
+                    rtxprintln!(out, "  set by {source}");
+                    set = true;
+                }
+                for (rtv, v) in runtimes {
+                    if !set && v == value {
+                        rtxprintln!(out, "  set by {rtv} (bin/exec-env)");
+                        set = true;
+                    } else {
+                        rtxprintln!(out, "  overrides {v} from {rtv} (bin/exec-env)");
+                    }
+                }
+                match pristine {
+                    Some(v) if v != value => {
+                        rtxprintln!(
+                            out,
+                            "  overrides {v} from the environment rtx was started in"
+                        )
+                    }
+                    _ => {}
+                }
+            }
+            None if config.env_remove.contains(key) => {
+                rtxprintln!(out, "{key} (unset)");
+                if let Some(source) = config.env_sources.get(key) {
+                    rtxprintln!(out, "  unset by {source}");
+                }
+                for (rtv, v) in runtimes {
+                    rtxprintln!(out, "  removes {v} from {rtv} (bin/exec-env)");
+                }
+                if let Some(v) = pristine {
+                    rtxprintln!(out, "  removes {v} from the environment rtx was started in");
+                }
+            }
+            None => match pristine {
+                Some(v) => {
+                    rtxprintln!(out, "{key}={v}");
+                    rtxprintln!(
+                        out,
+                        "  from the environment rtx was started in, rtx does not set it"
+                    );
+                }
+                None => rtxprintln!(out, "{key} is not set"),
+            },
+        }
+        if var.is_some() {
+            explain_diff_for_var(key, out);
+        }
+    }
+    if var.is_none() {
+        explain_path(config, out)?;
+    } else if var == Some("PATH") {
+        explain_path(config, out)?;
+        explain_diff_for_var("PATH", out);
+    }
+    if var.is_none() {
+        explain_diff(out);
+    }
+    Ok(())
+}
+
+/// each PATH entry in order with what added it
+fn explain_path(config: &Config, out: &mut Output) -> Result<()> {
+    let mut entries = vec![];
+    for path in &config.env_path {
+        entries.push((path.clone(), "env_path".to_string()));
+    }
+    for rtv in config.ts.list_current_installed_versions() {
+        for path in rtv.list_bin_paths()? {
+            entries.push((path, format!("{rtv} (bin/list-bin-paths)")));
+        }
+    }
+    for path in &config.env_path_after {
+        entries.push((path.clone(), "env_path_after".to_string()));
+    }
+    for path in env::PATH.iter() {
+        entries.push((
+            path.clone(),
+            "the environment rtx was started in".to_string(),
+        ));
+    }
+    let entries = entries
+        .into_iter()
+        .unique_by(|(path, _)| path.clone())
+        .map(|(path, source)| (display_path(&path), source))
+        .collect_vec();
+    let width = entries
+        .iter()
+        .map(|(path, _)| path.len())
+        .max()
+        .unwrap_or(0);
+    rtxprintln!(out, "PATH");
+    for (path, source) in entries {
+        rtxprintln!(out, "  {path:width$}  {source}");
+    }
+    Ok(())
+}
+
+/// what `rtx deactivate` or the next prompt outside of this directory restores from __RTX_DIFF
+fn explain_diff(out: &mut Output) {
+    let diff = &*env::__RTX_DIFF;
+    if diff.old.is_empty() && diff.new.is_empty() && diff.path.is_empty() {
+        rtxprintln!(
+            out,
+            "__RTX_DIFF is empty, rtx has not changed the environment of this shell"
+        );
+        return;
+    }
+    rtxprintln!(
+        out,
+        "__RTX_DIFF restores when leaving this directory or deactivating"
+    );
+    for patch in diff
+        .reverse()
+        .to_patches()
+        .into_iter()
+        .sorted_by_cached_key(patch_key)
+    {
+        match patch {
+            EnvDiffOperation::Add(k, v) | EnvDiffOperation::Change(k, v) => {
+                rtxprintln!(out, "  {k}={v}")
+            }
+            EnvDiffOperation::Remove(k) => rtxprintln!(out, "  {k} (unset)"),
+        }
+    }
+    for path in &diff.path {
+        rtxprintln!(out, "  PATH without {}", display_path(path));
+    }
+}
+
+fn explain_diff_for_var(key: &str, out: &mut Output) {
+    let diff = &*env::__RTX_DIFF;
+    if key == "PATH" {
+        for path in &diff.path {
+            rtxprintln!(
+                out,
+                "  {} is removed when leaving this directory (__RTX_DIFF)",
+                display_path(path)
+            );
+        }
+    } else if let Some(v) = diff.old.get(key) {
+        rtxprintln!(
+            out,
+            "  restored to {v} when leaving this directory (__RTX_DIFF)"
+        );
+    } else if diff.new.contains_key(key) {
+        rtxprintln!(out, "  unset when leaving this directory (__RTX_DIFF)");
+    }
+}
+
+fn patch_key(patch: &EnvDiffOperation) -> String {
+    match patch {
+        EnvDiffOperation::Add(k, _)
+        | EnvDiffOperation::Change(k, _)
+        | EnvDiffOperation::Remove(k) => k.clone(),
+    }
+}
+
+fn env_with_path(config: &Config) -> Result<IndexMap<String, String>> {
+    let mut env = config.env()?;
+    env.insert("PATH".into(), config.path_env()?);
+    Ok(env)
+}
+
+/// quotes a value for dotenv and Dockerfile ENV lines
+fn double_quote(v: &str) -> String {
+    format!("\"{}\"", escape(v))
+}
+
+/// escapes anything that would be expanded inside double quotes
+fn escape(v: &str) -> String {
+    v.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('$', "\\$")
+        .replace('\n', "\\n")
 }
 
 static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
@@ -58,6 +399,15 @@ static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
       $ eval "$(rtx env -s zsh)"
       $ rtx env -s fish | source
       $ execx($(rtx env -s xonsh))
+      $ rtx env --format json
+      $ rtx env --format github-actions
+      $ rtx env --format dockerfile >> Dockerfile
+
+      # show where the env vars and PATH entries come from
+      $ rtx env --explain
+      $ rtx env --explain=JAVA_HOME
+      JAVA_HOME=/Users/jdx/.local/share/rtx/installs/java/17.0.2
+        set by java@17.0.2 (bin/exec-env)
     "#, COLOR.header("Examples:")}
 });
 
@@ -66,8 +416,11 @@ mod tests {
     use crate::assert_cli;
     use crate::cli::tests::grep;
     use crate::dirs;
+    use crate::file::display_path;
     use pretty_assertions::assert_str_eq;
 
+    use super::double_quote;
+
     #[test]
     fn test_env() {
         assert_cli!("plugin", "add", "shfmt");
@@ -113,4 +466,39 @@ mod tests {
         let stdout = assert_cli!("env", "tiny@1", "-s", "bash");
         assert_str_eq!(grep(stdout, "JDXCODE"), "export JDXCODE_TINY=1.0.1");
     }
+
+    #[test]
+    fn test_env_dotenv() {
+        let stdout = assert_cli!("env", "tiny@1", "--format", "dotenv");
+        assert_str_eq!(grep(stdout, "JDXCODE"), "JDXCODE_TINY=\"1.0.1\"");
+    }
+
+    #[test]
+    fn test_env_json() {
+        let stdout = assert_cli!("env", "tiny@1", "--format", "json");
+        let env: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        assert_str_eq!(env["JDXCODE_TINY"].as_str().unwrap(), "1.0.1");
+    }
+
+    #[test]
+    fn test_env_explain() {
+        let stdout = assert_cli!("env", "tiny@1", "--explain=JDXCODE_TINY");
+        assert_str_eq!(
+            stdout,
+            "JDXCODE_TINY=1.0.1\n  set by tiny@1.0.1 (bin/exec-env)\n"
+        );
+        let stdout = assert_cli!("env", "tiny@1", "--explain");
+        let path = display_path(&dirs::ROOT.join("installs/tiny/1.0.1/bin"));
+        let line = stdout.lines().find(|l| l.trim_start().starts_with(&path));
+        assert!(line.unwrap().ends_with("tiny@1.0.1 (bin/list-bin-paths)"));
+        let stdout = assert_cli!("env", "tiny@1", "--explain=PATH");
+        assert!(stdout.starts_with("PATH\n"));
+        assert!(stdout.contains(&path));
+    }
+
+    #[test]
+    fn test_double_quote() {
+        assert_str_eq!(double_quote(r#"a "b" $c\d"#), r#""a \"b\" \$c\\d""#);
+        assert_str_eq!(double_quote("a\nb"), r#""a\nb""#);
+    }
 }
@@ -53,6 +53,9 @@ impl Command for Exec {
         let (program, args) = parse_command(&env::SHELL, self.command, self.c);
         let mut env = config.env()?;
         env.insert("PATH".into(), config.path_env()?);
+        for k in &config.env_remove {
+            env::remove_var(k);
+        }
 
         exec(program, args, env)
     }
@@ -39,7 +39,6 @@ pub fn execute(
             let args: Vec<String> = matches
                 .get_raw("args")
                 .unwrap_or_default()
-                .into_iter()
                 .map(|s| s.to_string_lossy().to_string())
                 .collect();
             plugin.execute_external_command(subcommand, args)?;
@@ -35,6 +35,7 @@ impl Command for HookEnv {
         self.clear_old_env(out);
         let env = config.env()?;
         let mut diff = EnvDiff::new(&env::PRISTINE_ENV, env);
+        diff.remove(&env::PRISTINE_ENV, config.env_remove.clone());
         let mut patches = diff.to_patches();
 
         let installs = config.list_paths()?; // load the active runtime paths
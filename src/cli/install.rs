@@ -4,7 +4,7 @@ use indoc::formatdoc;
 use once_cell::sync::Lazy;
 use owo_colors::Stream;
 
-use crate::cli::args::runtime::{RuntimeArg, RuntimeArgParser};
+use crate::cli::args::runtime::{RuntimeArg, RuntimeArgParser, RuntimeArgVersion};
 use crate::cli::command::Command;
 use crate::config::Config;
 use crate::config::MissingRuntimeBehavior::AutoInstall;
@@ -71,7 +71,7 @@ impl Install {
             let resolved_version = config.resolve_runtime_arg(&r)?;
             let plugin = config.ts.find_plugin(&r.plugin).unwrap();
             if let Some(resolved_version) = resolved_version {
-                let rtv = RuntimeVersion::new(plugin, &resolved_version);
+                let rtv = RuntimeVersion::new(plugin.clone(), &resolved_version);
 
                 if rtv.is_installed() && self.force {
                     rtv.uninstall()?;
@@ -83,7 +83,24 @@ impl Install {
                     continue;
                 }
 
-                self.do_install(&config, out, &rtv)?;
+                // checking the version needs bin/list-all, so it is only done up front if the
+                // versions are cached, otherwise only if the install fails
+                let unknown_version = |plugin: &Plugin| match &r.version {
+                    RuntimeArgVersion::Version(version)
+                        if !is_known_version(plugin, &resolved_version).unwrap_or(true) =>
+                    {
+                        Some(plugin.unknown_version(version, &config.aliases))
+                    }
+                    _ => None,
+                };
+                if plugin.has_cached_versions() {
+                    if let Some(err) = unknown_version(&plugin) {
+                        return Err(err.into());
+                    }
+                }
+                if let Err(err) = self.do_install(&config, out, &rtv) {
+                    return Err(unknown_version(&plugin).map_or(err, |e| e.into()));
+                }
             }
         }
 
@@ -132,6 +149,16 @@ impl Install {
     }
 }
 
+/// if the plugin lists `version` or a version starting with it, pre-releases included
+/// versions like "ref:main" which plugins handle themselves are not checked
+fn is_known_version(plugin: &Plugin, version: &str) -> Result<bool> {
+    if version.contains(':') || plugin.latest_version(version)?.is_some() {
+        return Ok(true);
+    }
+    let versions = plugin.list_remote_versions()?;
+    Ok(versions.iter().any(|v| v.starts_with(version)))
+}
+
 fn warn_plugin_not_installed(plugin: &Plugin) {
     warn!(
         "plugin {} is not installed. Install it with `rtx plugin add {}`",
@@ -163,7 +190,9 @@ static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
 
 #[cfg(test)]
 mod tests {
-    use crate::assert_cli;
+    use insta::assert_display_snapshot;
+
+    use crate::{assert_cli, assert_cli_err};
 
     #[test]
     fn test_install_force() {
@@ -180,4 +209,18 @@ mod tests {
         assert_cli!("install", "-f", "shfmt@my/alias");
         assert_cli!("where", "shfmt@my/alias");
     }
+
+    #[test]
+    fn test_install_unknown_plugin() {
+        let err = assert_cli_err!("install", "nodjs@20");
+        assert!(err
+            .to_string()
+            .starts_with("unknown plugin: nodjs. Did you mean nodejs"));
+    }
+
+    #[test]
+    fn test_install_unknown_version() {
+        let err = assert_cli_err!("install", "dummy@1.9");
+        assert_display_snapshot!(err, @"[dummy] no versions found matching 1.9. Did you mean 1.1.0, 1.0.0?");
+    }
 }
@@ -11,6 +11,8 @@ use crate::plugins::Plugin;
 use crate::ui::color::Color;
 
 /// get the latest runtime version of a plugin's runtimes
+///
+/// Uses the plugin's `bin/latest-stable` script if it has one.
 #[derive(Debug, clap::Args)]
 #[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
 pub struct Latest {
@@ -36,9 +38,9 @@ impl Command for Latest {
             _ => Err(eyre!("invalid version {}", self.runtime))?,
         };
         let plugin = Plugin::load_ensure_installed(&self.runtime.plugin, &config.settings)?;
-        let prefix = config.resolve_alias(&self.runtime.plugin, prefix);
+        let prefix = config.resolve_alias(&self.runtime.plugin, prefix)?;
 
-        if let Some(version) = plugin.latest_version(&prefix)? {
+        if let Some(version) = plugin.latest_stable_version(&prefix)? {
             rtxprintln!(out, "{}", version);
         }
         Ok(())
@@ -77,6 +79,12 @@ mod tests {
         assert!(stdout.starts_with("3."));
     }
 
+    #[test]
+    fn test_latest_stable() {
+        let stdout = assert_cli!("latest", "dummy@1");
+        assert_display_snapshot!(stdout, @"1.1.0");
+    }
+
     #[test]
     fn test_latest_asdf_format() {
         let stdout = assert_cli!("latest", "nodejs", "12");
@@ -14,8 +14,10 @@ mod activate;
 mod alias;
 pub mod args;
 mod asdf;
+mod cache;
 pub mod command;
 mod complete;
+mod config;
 mod current;
 mod deactivate;
 mod direnv;
@@ -31,10 +33,17 @@ mod local;
 mod ls;
 mod ls_remote;
 mod plugins;
+mod resolve;
+mod run;
 mod settings;
+mod shell;
+mod tasks;
+mod trust;
 mod uninstall;
+mod untrust;
 pub mod version;
 mod r#where;
+mod which;
 
 // render help
 #[cfg(debug_assertions)]
@@ -50,7 +59,9 @@ pub enum Commands {
     Activate(activate::Activate),
     Alias(alias::Alias),
     Asdf(asdf::Asdf),
+    Cache(cache::Cache),
     Complete(complete::Complete),
+    Config(config::ConfigCmd),
     Current(current::Current),
     Deactivate(deactivate::Deactivate),
     Direnv(direnv::Direnv),
@@ -65,10 +76,17 @@ pub enum Commands {
     Ls(ls::Ls),
     LsRemote(ls_remote::LsRemote),
     Plugins(plugins::Plugins),
+    Resolve(resolve::Resolve),
+    Run(run::Run),
     Settings(settings::Settings),
+    Shell(shell::Shell),
+    Tasks(tasks::Tasks),
+    Trust(trust::Trust),
     Uninstall(uninstall::Uninstall),
+    Untrust(untrust::Untrust),
     Version(version::Version),
     Where(r#where::Where),
+    Which(which::Which),
 
     #[cfg(debug_assertions)]
     RenderHelp(render_help::RenderHelp),
@@ -80,7 +98,9 @@ impl Commands {
             Self::Activate(cmd) => cmd.run(config, out),
             Self::Alias(cmd) => cmd.run(config, out),
             Self::Asdf(cmd) => cmd.run(config, out),
+            Self::Cache(cmd) => cmd.run(config, out),
             Self::Complete(cmd) => cmd.run(config, out),
+            Self::Config(cmd) => cmd.run(config, out),
             Self::Current(cmd) => cmd.run(config, out),
             Self::Deactivate(cmd) => cmd.run(config, out),
             Self::Direnv(cmd) => cmd.run(config, out),
@@ -95,10 +115,17 @@ impl Commands {
             Self::LsRemote(cmd) => cmd.run(config, out),
             Self::Local(cmd) => cmd.run(config, out),
             Self::Plugins(cmd) => cmd.run(config, out),
+            Self::Resolve(cmd) => cmd.run(config, out),
+            Self::Run(cmd) => cmd.run(config, out),
             Self::Settings(cmd) => cmd.run(config, out),
+            Self::Shell(cmd) => cmd.run(config, out),
+            Self::Tasks(cmd) => cmd.run(config, out),
+            Self::Trust(cmd) => cmd.run(config, out),
             Self::Uninstall(cmd) => cmd.run(config, out),
+            Self::Untrust(cmd) => cmd.run(config, out),
             Self::Version(cmd) => cmd.run(config, out),
             Self::Where(cmd) => cmd.run(config, out),
+            Self::Which(cmd) => cmd.run(config, out),
 
             #[cfg(debug_assertions)]
             Self::RenderHelp(cmd) => cmd.run(config, out),
@@ -132,6 +159,7 @@ impl Cli {
                 .subcommand_required(true)
                 .after_help(AFTER_HELP.as_str())
                 .arg(args::log_level::LogLevel::arg())
+                .arg(args::timings::Timings::arg())
                 .arg(args::verbose::Verbose::arg()),
         )
     }
@@ -0,0 +1,68 @@
+use atty::Stream;
+use color_eyre::eyre::Result;
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::output::Output;
+use crate::plugins::Plugin;
+use crate::ui::color::Color;
+
+/// shows the documentation for a plugin
+///
+/// This is the output of the plugin's `bin/help.overview`, `bin/help.deps`, `bin/help.config`
+/// and `bin/help.links` scripts.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct PluginsDoc {
+    /// plugin to show the documentation for
+    #[clap()]
+    plugin: String,
+
+    /// show documentation specific to this version
+    #[clap()]
+    version: Option<String>,
+}
+
+impl Command for PluginsDoc {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let plugin = Plugin::load_ensure_installed(&self.plugin, &config.settings)?;
+        rtxprintln!(out, "{}", plugin.help(self.version.as_deref())?);
+        Ok(())
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx plugins doc nodejs
+      $ rtx plugins doc nodejs 20.0.0
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+
+    use crate::{assert_cli, assert_cli_err};
+
+    #[test]
+    fn test_plugins_doc() {
+        let stdout = assert_cli!("plugins", "doc", "dummy", "1.0.0");
+        assert_display_snapshot!(stdout, @r###"
+        Dummy plugin documentation
+
+        Dummy plugin is a plugin only used for unit tests
+
+        Details specific for version 1.0.0
+        "###);
+    }
+
+    #[test]
+    fn test_plugins_doc_missing() {
+        let err = assert_cli_err!("plugins", "doc", "tiny");
+        assert_display_snapshot!(err, @"no documentation for plugin tiny");
+    }
+}
@@ -109,8 +109,8 @@ fn get_name_and_url(name: String, git_url: Option<String>) -> Result<(String, St
 
 fn get_name_from_url(url: &str) -> Result<String> {
     if let Ok(url) = Url::parse(url) {
-        if let Some(segments) = url.path_segments() {
-            let last = segments.last().unwrap_or_default();
+        if let Some(mut segments) = url.path_segments() {
+            let last = segments.next_back().unwrap_or_default();
             let name = last.strip_prefix("asdf-").unwrap_or(last);
             return Ok(name.to_string());
         }
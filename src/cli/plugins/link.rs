@@ -0,0 +1,105 @@
+use std::path::PathBuf;
+
+use atty::Stream;
+use color_eyre::eyre::{eyre, Result};
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::file::display_path;
+use crate::output::Output;
+use crate::plugins::Plugin;
+use crate::ui::color::{cyan, Color};
+
+/// symlinks a plugin into rtx
+///
+/// This is used for developing a plugin: changes in the directory are picked up
+/// immediately and `rtx plugins update` leaves it alone.
+/// Remove the link with `rtx plugins unlink`.
+#[derive(Debug, clap::Args)]
+#[clap(visible_alias = "ln", verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct PluginsLink {
+    /// The name of the plugin
+    ///
+    /// e.g.: nodejs, ruby
+    #[clap()]
+    name: String,
+
+    /// The local path to the plugin
+    ///
+    /// e.g.: ./asdf-nodejs
+    #[clap(value_hint = clap::ValueHint::DirPath)]
+    path: PathBuf,
+
+    /// Replace the plugin if it already exists
+    #[clap(short, long)]
+    force: bool,
+}
+
+impl Command for PluginsLink {
+    fn run(self, _config: Config, out: &mut Output) -> Result<()> {
+        let plugin = Plugin::new(&self.name);
+        if plugin.is_installed() && !self.force {
+            return Err(eyre!(
+                "plugin {} already exists, use --force to replace it",
+                cyan(Stream::Stderr, &self.name)
+            ));
+        }
+        plugin.link(&self.path)?;
+        rtxprintln!(
+            out,
+            "linked {} to {}",
+            self.name,
+            display_path(&plugin.link_target().unwrap())
+        );
+        Ok(())
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx plugins link nodejs ./asdf-nodejs
+      linked nodejs to ~/src/asdf-nodejs
+
+      # replace an installed plugin with a local checkout
+      $ rtx plugins link --force nodejs ./asdf-nodejs
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+
+    use crate::{assert_cli, assert_cli_err, dirs};
+
+    #[test]
+    fn test_plugin_link() {
+        let path = dirs::HOME.join("link-plugin");
+        std::fs::create_dir_all(path.join("bin")).unwrap();
+        std::fs::copy(
+            dirs::PLUGINS.join("tiny/bin/list-all"),
+            path.join("bin/list-all"),
+        )
+        .unwrap();
+        let err = assert_cli_err!("plugins", "link", "tiny", path.to_str().unwrap());
+        assert_display_snapshot!(err, @"plugin tiny already exists, use --force to replace it");
+
+        assert_cli!("plugins", "link", "linked", path.to_str().unwrap());
+        let stdout = assert_cli!("plugins", "ls");
+        assert!(stdout.contains("linked                        -> ~/link-plugin"));
+        assert_cli!("plugins", "unlink", "linked");
+        assert!(path.join("bin/list-all").exists());
+        std::fs::remove_dir_all(path).unwrap();
+    }
+
+    #[test]
+    fn test_plugin_link_not_a_plugin() {
+        let err = assert_cli_err!("plugins", "link", "linked", dirs::HOME.to_str().unwrap());
+        assert!(err
+            .to_string()
+            .ends_with("is not a plugin: bin/list-all not found"));
+    }
+}
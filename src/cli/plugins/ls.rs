@@ -6,12 +6,15 @@ use once_cell::sync::Lazy;
 use crate::cli::command::Command;
 use crate::cli::plugins::ls_remote::PluginsLsRemote;
 use crate::config::Config;
+use crate::file::display_path;
+use crate::git::Git;
 use crate::output::Output;
 use crate::ui::color::Color;
 
 /// List installed plugins
 ///
 /// Can also show remotely available plugins to install.
+/// Plugins added with `rtx plugins link` are shown with the directory they link to.
 #[derive(Debug, clap::Args)]
 #[clap(visible_alias = "list", after_long_help = AFTER_LONG_HELP.as_str(), verbatim_doc_comment)]
 pub struct PluginsLs {
@@ -26,6 +29,12 @@ pub struct PluginsLs {
     /// e.g.: https://github.com/asdf-vm/asdf-nodejs.git
     #[clap(short, long)]
     pub urls: bool,
+
+    /// show the git branch and commit of each plugin
+    ///
+    /// a "*" after the commit means the plugin has local modifications
+    #[clap(long)]
+    pub refs: bool,
 }
 
 impl Command for PluginsLs {
@@ -35,18 +44,39 @@ impl Command for PluginsLs {
         }
 
         for plugin in config.ts.list_installed_plugins() {
+            let mut columns = vec![];
             if self.urls {
-                if let Some(url) = plugin.get_remote_url() {
-                    rtxprintln!(out, "{:29} {}", plugin.name, url);
-                    continue;
-                }
+                columns.extend(plugin.get_remote_url());
+            }
+            if self.refs {
+                columns.extend(git_ref(&Git::new(plugin.plugin_path.clone())));
+            }
+            if let Some(target) = plugin.link_target() {
+                columns.push(format!("-> {}", display_path(&target)));
+            }
+            match columns.is_empty() {
+                true => rtxprintln!(out, "{}", plugin.name),
+                false => rtxprintln!(out, "{:29} {}", plugin.name, columns.join(" ")),
             }
-            rtxprintln!(out, "{}", plugin.name);
         }
         Ok(())
     }
 }
 
+/// e.g.: "main 5d2ec4f" or "main 5d2ec4f*" if there are local modifications
+fn git_ref(git: &Git) -> Option<String> {
+    if !git.is_repo() {
+        return None;
+    }
+    let branch = git.current_branch().ok()?;
+    let sha = git.current_abbrev_sha().ok()?;
+    let dirty = match git.is_dirty().unwrap_or_default() {
+        true => "*",
+        false => "",
+    };
+    Some(format!("{branch} {sha}{dirty}"))
+}
+
 static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
 static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
     formatdoc! {r#"
@@ -58,6 +88,15 @@ static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
       $ rtx plugins ls --urls
       nodejs                        https://github.com/asdf-vm/asdf-nodejs.git
       ruby                          https://github.com/asdf-vm/asdf-ruby.git
+
+      $ rtx plugins ls --refs
+      nodejs                        master 5d2ec4f
+      ruby                          master a1b3e2c*
+
+      $ rtx plugins link nodejs ~/src/asdf-nodejs
+      $ rtx plugins ls
+      nodejs                        -> ~/src/asdf-nodejs
+      ruby
     "#, COLOR.header("Examples:")}
 });
 
@@ -83,6 +122,12 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_plugin_list_refs() {
+        let stdout = assert_cli!("plugin", "list", "--refs");
+        assert_eq!(grep(stdout, "shfmt").split_whitespace().count(), 3);
+    }
+
     #[test]
     fn test_plugin_list_all() {
         let stdout = assert_cli!("plugin", "list", "--all", "--urls");
@@ -5,10 +5,14 @@ use crate::cli::command::Command;
 use crate::config::Config;
 use crate::output::Output;
 
+mod doc;
 mod install;
+mod link;
 mod ls;
 mod ls_remote;
+mod test;
 mod uninstall;
+mod unlink;
 mod update;
 
 #[derive(Debug, clap::Args)]
@@ -28,24 +32,38 @@ pub struct Plugins {
     /// e.g.: https://github.com/asdf-vm/asdf-nodejs.git
     #[clap(short, long)]
     pub urls: bool,
+
+    /// show the git branch and commit of each plugin
+    ///
+    /// a "*" after the commit means the plugin has local modifications
+    #[clap(long)]
+    pub refs: bool,
 }
 
 #[derive(Debug, Subcommand)]
 enum Commands {
+    Doc(doc::PluginsDoc),
     Install(install::PluginsInstall),
+    Link(link::PluginsLink),
     Ls(ls::PluginsLs),
     LsRemote(ls_remote::PluginsLsRemote),
+    Test(test::PluginsTest),
     Uninstall(uninstall::PluginsUninstall),
+    Unlink(unlink::PluginsUnlink),
     Update(update::Update),
 }
 
 impl Commands {
     pub fn run(self, config: Config, out: &mut Output) -> Result<()> {
         match self {
+            Self::Doc(cmd) => cmd.run(config, out),
             Self::Install(cmd) => cmd.run(config, out),
+            Self::Link(cmd) => cmd.run(config, out),
             Self::Ls(cmd) => cmd.run(config, out),
             Self::LsRemote(cmd) => cmd.run(config, out),
+            Self::Test(cmd) => cmd.run(config, out),
             Self::Uninstall(cmd) => cmd.run(config, out),
+            Self::Unlink(cmd) => cmd.run(config, out),
             Self::Update(cmd) => cmd.run(config, out),
         }
     }
@@ -56,6 +74,7 @@ impl Command for Plugins {
         let cmd = self.command.unwrap_or(Commands::Ls(ls::PluginsLs {
             all: self.all,
             urls: self.urls,
+            refs: self.refs,
         }));
 
         cmd.run(config, out)
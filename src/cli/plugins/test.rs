@@ -0,0 +1,315 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use atty::Stream;
+use color_eyre::eyre::{eyre, Result};
+use duct::cmd;
+use indoc::formatdoc;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::errors::Error::PluginNotInstalled;
+use crate::file::{basename, is_executable};
+use crate::output::Output;
+use crate::plugins::{InstallType, Plugin, Script};
+use crate::runtimes::RuntimeVersion;
+use crate::ui::color::{dimmed, green, red, Color};
+use crate::{dirs, env, file};
+
+/// runs a plugin through a set of conformance checks
+///
+/// The plugin is copied into a temporary data directory so none of the installed plugins,
+/// runtimes or config files are used or modified. The checks are:
+///
+/// - list-all prints whitespace-separated versions
+/// - list-legacy-filenames prints whitespace-separated filenames
+/// - list-aliases prints "<alias> <version>" lines
+/// - a version can be installed
+/// - the list-bin-paths directories exist and contain executables
+/// - exec-env can be sourced and sets valid env var names
+///
+/// Exits with 1 if any check fails.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct PluginsTest {
+    /// The name of an installed plugin or the path to a plugin
+    ///
+    /// e.g.: nodejs, ./asdf-nodejs
+    #[clap()]
+    plugin: String,
+
+    /// The version to install, defaults to the latest stable version
+    #[clap()]
+    version: Option<String>,
+
+    /// run the checks in the current data directory, used once the temporary one is set up
+    #[clap(long, hide = true)]
+    isolated: bool,
+}
+
+impl Command for PluginsTest {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        if self.isolated {
+            return run_checks(&config, out, &self.plugin, self.version, &dirs::ROOT);
+        }
+        let (name, source) = self.plugin_source()?;
+        let tmp = env::RTX_TMP_DIR.join(format!("plugins-test-{}", std::process::id()));
+        let plugins_dir = tmp.join("data/plugins");
+        fs::create_dir_all(&plugins_dir)?;
+        let mut args = vec!["plugins".to_string(), "test".into(), "--isolated".into()];
+        args.push(name.clone());
+        args.extend(self.version);
+        let status = cmd!("cp", "-R", &source, plugins_dir.join(&name))
+            .run()
+            .and_then(|_| {
+                cmd(&*env::RTX_EXE, args)
+                    .dir(&tmp)
+                    .env("RTX_DATA_DIR", tmp.join("data"))
+                    .env("RTX_CONFIG_DIR", tmp.join("config"))
+                    .env("RTX_MISSING_RUNTIME_BEHAVIOR", "ignore")
+                    .unchecked()
+                    .run()
+            });
+        let _ = fs::remove_dir_all(&tmp);
+        match status?.status.code() {
+            Some(0) => {}
+            code => {
+                out.status = ExitCode::from(code.and_then(|c| u8::try_from(c).ok()).unwrap_or(1))
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PluginsTest {
+    /// the plugin name and directory to test. A path is named after its directory
+    /// without the "asdf-" prefix, e.g.: ./asdf-nodejs is tested as nodejs
+    fn plugin_source(&self) -> Result<(String, PathBuf)> {
+        let path = PathBuf::from(&self.plugin);
+        if self.plugin.contains('/') || path.is_dir() {
+            let path = path
+                .canonicalize()
+                .map_err(|_| eyre!("{} does not exist", self.plugin))?;
+            let name = basename(&path).unwrap();
+            let name = name.strip_prefix("asdf-").unwrap_or(&name).to_string();
+            return Ok((name, path));
+        }
+        let plugin = Plugin::new(&self.plugin);
+        if !plugin.is_installed() {
+            return Err(PluginNotInstalled(self.plugin.clone()).into());
+        }
+        let path = plugin.plugin_path.canonicalize()?;
+        Ok((plugin.name, path))
+    }
+}
+
+enum Check {
+    Pass(String),
+    Fail(String),
+    Skip(String),
+}
+
+/// runs the checks, installing the version into `data_dir`
+fn run_checks(
+    config: &Config,
+    out: &mut Output,
+    name: &str,
+    version: Option<String>,
+    data_dir: &Path,
+) -> Result<()> {
+    let plugin = Arc::new(Plugin::new(&name.to_string()));
+    if !plugin.is_installed() {
+        return Err(PluginNotInstalled(name.to_string()).into());
+    }
+    let mut failed = 0;
+    let mut report = |out: &mut Output, check: &str, result: Check| {
+        let line = match result {
+            Check::Pass(msg) => format!("{} {check}: {msg}", green(Stream::Stdout, "✓")),
+            Check::Fail(msg) => {
+                failed += 1;
+                format!("{} {check}: {msg}", red(Stream::Stdout, "✗"))
+            }
+            Check::Skip(msg) => dimmed(Stream::Stdout, &format!("- {check}: {msg}")),
+        };
+        rtxprintln!(out, "{}", line);
+    };
+
+    report(out, "list-all", check_list_all(&plugin));
+    report(
+        out,
+        "list-legacy-filenames",
+        check_legacy_filenames(&plugin),
+    );
+    report(out, "list-aliases", check_aliases(&plugin));
+
+    let version = match version {
+        Some(version) => Some(version),
+        None => plugin.latest_stable_version("latest").unwrap_or_default(),
+    };
+    let rtv = version.map(|v| RuntimeVersion::new_in(plugin.clone(), &v, data_dir));
+    let installed = match &rtv {
+        Some(rtv) => match rtv.install(InstallType::Version, config) {
+            Ok(()) => {
+                report(out, "install", Check::Pass(rtv.to_string()));
+                true
+            }
+            Err(err) => {
+                report(out, "install", Check::Fail(format!("{rtv}: {err}")));
+                false
+            }
+        },
+        None => {
+            report(out, "install", Check::Fail("no version to install".into()));
+            false
+        }
+    };
+    match rtv.filter(|_| installed) {
+        Some(rtv) => {
+            report(out, "list-bin-paths", check_bin_paths(&rtv));
+            report(out, "exec-env", check_exec_env(&rtv));
+        }
+        None => {
+            report(out, "list-bin-paths", Check::Skip("not installed".into()));
+            report(out, "exec-env", Check::Skip("not installed".into()));
+        }
+    }
+
+    if failed > 0 {
+        out.status = ExitCode::from(1);
+    }
+    Ok(())
+}
+
+fn check_list_all(plugin: &Plugin) -> Check {
+    let versions = match plugin.read_script(Script::ListAll) {
+        Ok(Some(output)) => output.split_whitespace().map(String::from).collect_vec(),
+        Ok(None) => return Check::Fail("bin/list-all not found".into()),
+        Err(err) => return Check::Fail(format!("{err:#}")),
+    };
+    if versions.is_empty() {
+        return Check::Fail("no versions".into());
+    }
+    if let Some(v) = versions.iter().find(|v| v.chars().any(|c| c.is_control())) {
+        return Check::Fail(format!("invalid version: {v:?}"));
+    }
+    if let Some(v) = versions.iter().duplicates().next() {
+        return Check::Fail(format!("duplicate version: {v}"));
+    }
+    Check::Pass(format!("{} versions", versions.len()))
+}
+
+fn check_legacy_filenames(plugin: &Plugin) -> Check {
+    let filenames = match plugin.read_script(Script::ListLegacyFilenames) {
+        Ok(Some(output)) => output.split_whitespace().map(String::from).collect_vec(),
+        Ok(None) => return Check::Skip("no bin/list-legacy-filenames".into()),
+        Err(err) => return Check::Fail(format!("{err:#}")),
+    };
+    if let Some(f) = filenames.iter().find(|f| f.contains('/')) {
+        return Check::Fail(format!("invalid filename: {f}"));
+    }
+    Check::Pass(filenames.join(" "))
+}
+
+fn check_aliases(plugin: &Plugin) -> Check {
+    let output = match plugin.read_script(Script::ListAliases) {
+        Ok(Some(output)) => output,
+        Ok(None) => return Check::Skip("no bin/list-aliases".into()),
+        Err(err) => return Check::Fail(format!("{err:#}")),
+    };
+    let lines = output
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .collect_vec();
+    if let Some(line) = lines.iter().find(|l| l.split_whitespace().count() != 2) {
+        return Check::Fail(format!("expected \"<alias> <version>\", got: {line:?}"));
+    }
+    Check::Pass(format!("{} aliases", lines.len()))
+}
+
+fn check_bin_paths(rtv: &RuntimeVersion) -> Check {
+    let bin_paths = match rtv.list_bin_paths() {
+        Ok(bin_paths) => bin_paths,
+        Err(err) => return Check::Fail(format!("{err:#}")),
+    };
+    for path in &bin_paths {
+        if !path.is_dir() {
+            return Check::Fail(format!("{} is not a directory", path.display()));
+        }
+        let has_executables = file::dir_files(path)
+            .unwrap_or_default()
+            .iter()
+            .any(|f| is_executable(&path.join(f)));
+        if !has_executables {
+            return Check::Fail(format!("no executables in {}", path.display()));
+        }
+    }
+    let relative = bin_paths
+        .iter()
+        .map(|p| p.strip_prefix(&rtv.install_path).unwrap_or(p).display())
+        .join(" ");
+    Check::Pass(relative)
+}
+
+fn check_exec_env(rtv: &RuntimeVersion) -> Check {
+    if !rtv.plugin.plugin_path.join("bin/exec-env").exists() {
+        return Check::Skip("no bin/exec-env".into());
+    }
+    let env = match rtv.exec_env() {
+        Ok(env) => env,
+        Err(err) => return Check::Fail(format!("{err:#}")),
+    };
+    let is_valid_name = |k: &String| {
+        !k.is_empty()
+            && !k.starts_with(|c: char| c.is_ascii_digit())
+            && k.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    };
+    if let Some(k) = env.keys().find(|k| !is_valid_name(k)) {
+        return Check::Fail(format!("invalid env var name: {k:?}"));
+    }
+    Check::Pass(env.keys().sorted().join(" "))
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx plugins test ./asdf-nodejs
+      ✓ list-all: 694 versions
+      ✓ list-legacy-filenames: .nvmrc .node-version
+      ✓ list-aliases: 3 aliases
+      ✓ install: nodejs@19.8.1
+      ✓ list-bin-paths: bin
+      - exec-env: no bin/exec-env
+
+      $ rtx plugins test nodejs 18.15.0
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+
+    use super::*;
+
+    #[test]
+    fn test_plugin_test() {
+        let tmp = tempfile::tempdir().unwrap();
+        let config = Config::load().unwrap();
+        let mut out = Output::tracked();
+        run_checks(&config, &mut out, "dummy", Some("1.0.0".into()), tmp.path()).unwrap();
+        assert!(tmp.path().join("installs/dummy/1.0.0/bin").is_dir());
+        assert!(!dirs::INSTALLS.join("dummy").exists());
+        assert_display_snapshot!(out.stdout.content, @r###"
+        ✓ list-all: 3 versions
+        ✓ list-legacy-filenames: .dummy-version .dummyrc
+        - list-aliases: no bin/list-aliases
+        ✓ install: dummy@1.0.0
+        ✓ list-bin-paths: bin
+        - exec-env: no bin/exec-env
+        "###);
+    }
+}
@@ -0,0 +1,50 @@
+use atty::Stream;
+use color_eyre::eyre::Result;
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::output::Output;
+use crate::plugins::Plugin;
+use crate::ui::color::Color;
+
+/// removes a plugin linked with `rtx plugins link`
+///
+/// The linked directory and the installed runtime versions are kept.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct PluginsUnlink {
+    /// plugin to unlink
+    #[clap()]
+    plugin: String,
+}
+
+impl Command for PluginsUnlink {
+    fn run(self, _config: Config, out: &mut Output) -> Result<()> {
+        Plugin::new(&self.plugin).unlink()?;
+        rtxprintln!(out, "unlinked {}", self.plugin);
+        Ok(())
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx plugins unlink nodejs
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+
+    use crate::assert_cli_err;
+
+    #[test]
+    fn test_plugin_unlink_not_linked() {
+        let err = assert_cli_err!("plugins", "unlink", "tiny");
+        assert_display_snapshot!(err, @"tiny is not a linked plugin");
+    }
+}
@@ -1,19 +1,24 @@
 use std::sync::Arc;
+use std::thread;
 
 use color_eyre::eyre::{eyre, Result};
 use indoc::formatdoc;
+use itertools::Itertools;
 use once_cell::sync::Lazy;
 use owo_colors::Stream;
 
 use crate::cli::command::Command;
 use crate::config::Config;
 use crate::output::Output;
-use crate::plugins::Plugin;
-use crate::ui::color::{cyan, Color};
+use crate::plugins::{unknown_plugin, Plugin};
+use crate::ui::color::Color;
 
-/// updates a plugin to the latest version
+/// updates plugins to the latest version
 ///
-/// note: this updates the plugin itself, not the runtime versions
+/// note: this updates the plugins themselves, not the runtime versions
+///
+/// All plugins are updated in parallel if none are specified. The commits pulled in
+/// for each plugin are shown.
 #[derive(Debug, clap::Args)]
 #[clap(verbatim_doc_comment, alias = "upgrade", after_long_help = AFTER_LONG_HELP.as_str())]
 pub struct Update {
@@ -22,30 +27,64 @@ pub struct Update {
     plugin: Option<Vec<String>>,
 
     /// update all plugins
+    ///
+    /// this is the default if no plugins are specified
     #[clap(long, short = 'a', conflicts_with = "plugin")]
     all: bool,
+
+    /// only fetch and show the commits that would be pulled in
+    #[clap(long, short = 'n')]
+    dry_run: bool,
 }
 
 impl Command for Update {
     fn run(self, config: Config, out: &mut Output) -> Result<()> {
-        let plugins: Vec<Arc<Plugin>> = match (self.plugin, self.all) {
-            (Some(plugins), _) => plugins
+        let plugins: Vec<Arc<Plugin>> = match self.plugin {
+            Some(plugins) => plugins
                 .into_iter()
-                .map(|p| {
-                    config.ts.find_plugin(&p).ok_or_else(|| {
-                        eyre!("plugin {} not found", cyan(Stream::Stderr, p.as_str()))
-                    })
-                })
-                .collect::<Result<Vec<Arc<Plugin>>>>()?,
-            (_, true) => config.ts.list_installed_plugins(),
-            _ => Err(eyre!("no plugins specified"))?,
+                .map(|p| config.ts.find_plugin(&p).ok_or_else(|| unknown_plugin(&p)))
+                .collect::<Result<Vec<Arc<Plugin>>, _>>()?,
+            None => config.ts.list_installed_plugins(),
         };
+        let (linked, plugins): (Vec<_>, Vec<_>) = plugins.into_iter().partition(|p| p.is_linked());
+        for plugin in linked {
+            rtxprintln!(out, "{}: linked, not updating", plugin.name);
+        }
 
-        for plugin in plugins {
-            rtxprintln!(out, "updating plugin {}", plugin.name);
-            plugin.update(None)?;
+        // updates spend their time waiting on git so use a thread per plugin
+        // rather than the rayon pool which is sized by the number of cpus
+        let results = thread::scope(|s| {
+            plugins
+                .iter()
+                .map(|plugin| s.spawn(|| plugin.update(None, self.dry_run)))
+                .collect_vec()
+                .into_iter()
+                .map(|h| h.join().unwrap())
+                .collect_vec()
+        });
+        let mut failed = vec![];
+        for (plugin, result) in plugins.iter().zip(results) {
+            let log = match result {
+                Ok(log) => log,
+                Err(e) => {
+                    rtxprintln!(out, "{}: failed", plugin.name);
+                    failed.push(format!("failed to update {}: {e:#}", plugin.name));
+                    continue;
+                }
+            };
+            match (log.is_empty(), self.dry_run) {
+                (true, _) => rtxprintln!(out, "{}: already up to date", plugin.name),
+                (false, true) => rtxprintln!(out, "{}: {} new commit(s)", plugin.name, log.len()),
+                (false, false) => rtxprintln!(out, "{}: updated", plugin.name),
+            }
+            for line in log {
+                rtxprintln!(out, "  {}", line);
+            }
+        }
+        match failed.is_empty() {
+            true => Ok(()),
+            false => Err(eyre!(failed.join("\n"))),
         }
-        Ok(())
     }
 }
 
@@ -53,22 +92,29 @@ static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
 static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
     formatdoc! {r#"
     {}
-      $ rtx plugins update --all   # update all plugins
-      $ rtx plugins update nodejs  # update only nodejs
+      $ rtx plugins update            # update all plugins
+      $ rtx plugins update nodejs     # update only nodejs
+      $ rtx plugins update --dry-run  # show the commits that would be pulled in
+      nodejs: 2 new commit(s)
+        5d2ec4f fix: use the lts alias for the latest lts version
+        a1b3e2c feat: add list-bin-paths
     "#, COLOR.header("Examples:")}
 });
 
 #[cfg(test)]
 mod tests {
-    use pretty_assertions::assert_str_eq;
-
-    use crate::{assert_cli, assert_cli_err};
+    use crate::assert_cli;
 
     #[test]
     fn test_plugin_update() {
-        let err = assert_cli_err!("p", "update");
-        assert_str_eq!(err.to_string(), "no plugins specified");
+        assert_cli!("p", "update");
         assert_cli!("plugin", "update", "--all");
         assert_cli!("plugins", "update", "nodejs");
     }
+
+    #[test]
+    fn test_plugin_update_dry_run() {
+        let stdout = assert_cli!("plugins", "update", "--dry-run", "tiny");
+        assert!(stdout.starts_with("tiny: "));
+    }
 }
@@ -369,12 +369,119 @@ plugin_autoupdate_last_check_duration = 10080 # (one week) set to 0 to disable u
 
 verbose = false # see explanation under `RTX_VERBOSE`
 
+# directory or http(s) url to reuse prebuilt runtimes from, see "Binary Cache"
+# binary_cache = '/mnt/rtx-cache'
+
 [alias.nodejs]
 my_custom_node = '18'  # makes `rtx install nodejs@my_custom_node` install node-18.x
                        # this can also be specified in a plugin (see below in "Aliases")
 ```
 
-These settings can also be managed with `rtx settings ls|get|set|unset`.
+These settings can also be managed with `rtx settings ls|get|set|unset`. Any key in this file or
+in a project config file (with `--file`) can be changed with `rtx config get|set|unset`, which
+keeps the formatting and comments in the file:
+
+```sh-session
+$ rtx config set alias.nodejs.lts 18
+$ rtx config set --file .rtxrc.toml env.NODE_ENV production
+$ rtx config get --file .rtxrc.toml env.NODE_ENV
+production
+```
+
+If you are coming from asdf, `legacy_version_file` and `always_keep_download` are also read from
+`~/.asdfrc` (or `$ASDF_CONFIG_FILE`). Settings in `~/.config/rtx/config.toml` take precedence.
+
+### Project config: `.rtxrc.toml`
+
+`.rtxrc.toml` (or `.rtxrc`) files are found in the current directory and its parents just like
+`.tool-versions`. They can define environment variables that are set whenever the directory is
+active in an `[env]` section. Values are [tera](https://tera.netlify.app/docs/) templates:
+
+```toml
+[env]
+NODE_ENV = 'production'
+PROJECT_ROOT = '{{{{config_root}}}}'            # directory containing this file
+CACHE_DIR = '{{{{env.HOME}}}}/.cache/myproject' # any variable from the environment
+PYTHON_PATH = '{{{{runtimes.python.path}}}}'    # install path of the active python
+NODE_VERSION = 'node-{{{{runtimes.nodejs.version}}}}'
+NODE_OPTIONS = false                          # unsets NODE_OPTIONS in this directory
+```
+
+`env` contains the original environment as well as values set by config files loaded before this
+one. `runtimes.<plugin>.path` and `runtimes.<plugin>.version` are available for every active runtime.
+
+Vars set to `false` are unset by `rtx activate`, `rtx exec` and the shell output of `rtx env`.
+`rtx env --format` can't express an unset so it warns and leaves them as they are.
+
+Variables can also be loaded from [dotenv](https://github.com/motdotla/dotenv) files. They are
+loaded before `[env]` so they can be overridden or referenced there. Files that don't exist are
+skipped and rtx will pick up changes to them on the next prompt:
+
+```toml
+env_file = ['.env', '.env.local'] # or a single file: env_file = '.env'
+```
+
+Project directories can also be added to `PATH`. Relative paths are relative to the directory
+containing the config file:
+
+```toml
+env_path = ['./bin', './node_modules/.bin'] # added ahead of the runtime paths
+env_path_after = ['./scripts']              # added after the runtime paths
+```
+
+These are removed from `PATH` when leaving the directory just like runtime paths.
+
+### Profiles and local overrides
+
+Set `RTX_ENV` to load an extra config file for that environment. For example, `RTX_ENV=staging`
+will also load `.rtx.staging.toml`. `.rtx.local.toml` is always loaded and is intended for personal
+overrides that are not committed (add it to `.gitignore`). Both use the same format as `.rtxrc.toml`.
+
+In each directory, config files take precedence in the following order (highest first):
+
+1. `.rtx.local.toml`
+2. `.rtx.$RTX_ENV.toml`
+3. `.rtxrc.toml`
+4. `.rtxrc`
+5. `.tool-versions`
+6. legacy version files like `.nvmrc`
+
+Files in a directory take precedence over files in its parent directories. `rtx ls` shows which
+file set each version and `rtx config ls` lists every config file that was found along with the
+versions, env vars and settings it contributes and which of its versions were overridden.
+
+### Trusting config files
+
+Since project config files can change the environment, rtx won't load anything other than runtime
+versions from them until they are trusted. This prevents cloning a repository and `cd`-ing into it
+from silently setting variables or adding to `PATH`. Run `rtx trust` in the directory to trust its
+config files, or `rtx trust --hash <FILE>` to only trust the current contents so any change will
+need to be trusted again. `rtx untrust` reverts this. Trusted files are tracked in
+`~/.local/share/rtx/trusted-configs`.
+
+### Validating config files
+
+Any key in a config file that isn't a setting is treated as a plugin, so a typo like
+`legacy_version_files = true` would otherwise go unnoticed. `rtx config validate` checks
+`~/.config/rtx/config.toml` and the project config files in use (or a file passed to it) for
+unknown keys and values of the wrong type:
+
+```sh-session
+$ rtx config validate
+~/.config/rtx/config.toml: valid
+~/src/myproj/.rtxrc.toml:3:1: unknown key: legacy_version_files. Did you mean legacy_version_file?
+```
+
+Set `RTX_STRICT=1` to make these errors when loading config files.
+
+The files follow the JSON Schema in [schema/rtx.json](./schema/rtx.json), which is also printed by
+`rtx config schema`. Editors can use it for completion, for example with
+[Even Better TOML](https://marketplace.visualstudio.com/items?itemName=tamasfe.even-better-toml)
+by adding this to the top of the file:
+
+```toml
+#:schema https://raw.githubusercontent.com/jdxcode/rtx/main/schema/rtx.json
+```
 
 ### Environment variables
 
@@ -398,14 +505,24 @@ $ RTX_NODEJS_VERSION=20 rtx exec -- node --version
 This is the path to the config file. The default is `~/.config/rtx/config.toml`.
 (Or `$XDG_CONFIG_HOME/config.toml` if that is set)
 
+#### `RTX_ENV`
+
+Loads `.rtx.$RTX_ENV.toml` config files in addition to `.rtxrc.toml`. See
+[Profiles and local overrides](#profiles-and-local-overrides).
+
 #### `RTX_DEFAULT_TOOL_VERSIONS_FILENAME`
 
 Set to something other than ".tool-versions" to have rtx look for configuration with alternate names.
 
+#### `ASDF_CONFIG_FILE`
+
+Path to the asdf config file to read settings from. Defaults to `~/.asdfrc`.
+
 #### `RTX_${{PLUGIN}}_VERSION`
 
 Set the version for a runtime. For example, `RTX_NODEJS_VERSION=20` will use nodejs@20.x regardless
-of what is set in `.tool-versions`.
+of what is set in `.tool-versions`. `rtx shell nodejs@20` sets this for the current shell session.
+Dashes in plugin names become underscores, e.g.: `RTX_NODE_BUILD_VERSION`.
 
 #### `RTX_LEGACY_VERSION_FILE`
 
@@ -426,6 +543,23 @@ Output logs to a file.
 Same as `RTX_LOG_LEVEL` but for the log file output level. This is useful if you want
 to store the logs but not have them litter your display.
 
+#### `RTX_TIMINGS=1`
+
+Show how long each phase of loading config and each plugin script took, including the exit code of
+the scripts. Set it to a path ending in `.json`, e.g.: `RTX_TIMINGS=trace.json`, to write them in
+the Chrome trace format instead, which can be viewed in `chrome://tracing`. This can also be set
+with `--timings` or `--timings=trace.json`.
+
+#### `RTX_STRICT=1`
+
+Fail to load config files with unknown keys or values of the wrong type instead of treating unknown
+keys as plugins. See [Validating config files](#validating-config-files).
+
+#### `RTX_BINARY_CACHE`
+
+Directory or http(s) url of a cache of prebuilt runtimes. This overrides `binary_cache` in
+`~/.config/rtx/config.toml`. See [Binary Cache](#binary-cache).
+
 #### `RTX_VERBOSE=1`
 
 This shows the installation output during `rtx install` and `rtx plugin install`.
@@ -457,6 +591,11 @@ User aliases can be created by adding an `alias.<PLUGIN>` section to `~/.config/
 my_custom_18 = '18'
 ```
 
+Or with `rtx alias set nodejs my_custom_18 18`. Aliases can also be defined for a single project
+in `.rtxrc.toml` (`rtx alias set --local`), these take precedence over the global ones.
+An alias can point at another alias, e.g.: `lts = 'lts/hydrogen'`. Aliases that lead back to
+themselves are an error.
+
 Plugins can also provide aliases via a `bin/list-aliases` script. Here is an example showing node.js
 versions:
 
@@ -473,11 +612,29 @@ echo "lts/fermium 14"
 > Because this is rtx-specific functionality not currently used by asdf it isn't likely to be in any
 > plugin currently, but plugin authors can add this script without impacting asdf users.
 
+To see how a version is resolved through aliases, installed versions and the plugin's remote
+versions, use `rtx resolve --explain`. Versions are matched by prefix so this is helpful when
+something like `python 3.1` unexpectedly resolves to `3.10.x`:
+
+```sh-session
+$ rtx resolve nodejs@lts --explain
+```
+
 ## Plugins
 
 rtx uses asdf's plugin ecosystem under the hood. See https://github.com/asdf-vm/asdf-plugins for a
 list.
 
+Besides the scripts used to list and install versions, rtx supports these optional plugin scripts
+from asdf:
+
+- `bin/latest-stable` is used by `rtx latest` to find the latest stable version
+- `bin/exec-path` is used by `rtx which` to find the executable for a bin
+- `bin/help.overview`, `bin/help.deps`, `bin/help.config` and `bin/help.links` are shown by `rtx plugins doc`
+- `bin/post-plugin-add` runs after the plugin is installed
+- `bin/post-plugin-update` runs after `rtx plugins update` with `ASDF_PLUGIN_PREV_REF` and `ASDF_PLUGIN_POST_REF` set
+- `bin/pre-plugin-remove` runs before the plugin is uninstalled
+
 ## FAQs
 
 ### I don't want to put a `.tool-versions` file into my project since git shows it as an untracked file.
@@ -493,17 +650,39 @@ You can make git ignore these files in 3 different ways:
 Just follow the [asdf docs](https://asdf-vm.com/plugins/create.html). Everything should work the same.
 If it isn't, please open an issue.
 
+While developing a plugin, use `rtx plugins link <name> <path>` to use a local checkout in place of an
+installed plugin. Changes in the checkout take effect immediately. `rtx plugins unlink <name>` removes it.
+`rtx plugins test <name|path> [version]` checks that the scripts work with rtx by running them and
+installing a version in a temporary directory. It exits with 1 if any check fails so it can be used in CI.
+
 ### rtx is failing or not working right
 
 First try setting `RTX_LOG_LEVEL=debug` or `RTX_LOG_LEVEL=trace` and see if that gives you more information.
 You can also set `RTX_LOG_FILE=/path/to/logfile` to write the logs to a file.
+If rtx is slow, `RTX_TIMINGS=1` shows where the time is going.
 
 If something is happening with the activate hook, you can try disabling it and calling `eval "$(rtx hook-env)"` manually.
 It can also be helpful to use `rtx env` to see what environment variables it wants to use.
+`rtx env --explain` (or `rtx env --explain=VAR` for a single variable) shows where each of them
+comes from: a runtime's `bin/exec-env`, an `[env]` section or the environment rtx was started in.
 
 Lastly, there is an `rtx doctor` command. It doesn't have much in it but I hope to add more functionality
 to that to help debug issues.
 
+### How can scripts tell why rtx failed?
+
+These errors are shown with a code and exit with their own status. Other errors exit with 1.
+
+| Code | Exit status | Error                                                           |
+|------|-------------|-----------------------------------------------------------------|
+| E001 | 10          | plugin not installed                                            |
+| E002 | 11          | unknown plugin, with suggestions for plugins with similar names |
+| E003 | 12          | version not installed                                           |
+| E004 | 13          | no versions found matching the version, with suggestions        |
+| E005 | 14          | alias cycle                                                     |
+| E006 | 15          | config file could not be parsed                                 |
+| E007 | 16          | plugin script exited with a non-zero status                     |
+
 ### Windows support?
 
 This is unlikely to ever happen since this leverages the vast ecosystem of asdf plugins which are built on Bash scripts.
@@ -712,6 +891,11 @@ the case, we may need to make this cached instead of static.
 cat ~/.local/share/rtx/installs/nodejs/18.13.0/.rtxconf.msgpack | msgpack-cli decode
 ```
 
+Runtimes with a `bin/exec-env` script also store its output in `.rtxexecenv.msgpack` so `rtx hook-env`
+doesn't need to run bash for every runtime on every prompt. It is rerun when any file in the
+plugin's `bin/` or `lib/` changes, when `ASDF_*`, `HOME` or any env var those files reference
+changes, and after `rtx plugins update`. Linked plugins are never cached.
+
 ### Legacy File Cache
 
 If enabled, rtx will read the legacy filenames such as `.node-version` for
@@ -727,6 +911,35 @@ plugin is called:
     It will remain cached until the file is modified. This is a simple text file that has the path to the
     legacy file stored as a hash for the filename.
 
+### Binary Cache
+
+Some plugins like python and ruby compile runtimes from source which can take a long time. If
+`binary_cache` (or `RTX_BINARY_CACHE`) is set, finished installs can be shared between machines.
+`rtx cache push` uploads the install directory of a runtime as a tarball along with metadata about
+how it was built. `rtx install` then checks the cache before running the plugin's install script
+and unpacks the tarball instead if there is a match. `rtx cache pull` does the same but never
+falls back to building.
+
+The cache can be a directory (e.g.: on NFS) or an http(s) url. Uploading to an http cache uses `PUT`
+requests and downloading uses `GET`, both through `curl`. Files are stored as:
+
+```
+<CACHE>/<PLUGIN>/<VERSION>/<OS>-<ARCH>-<PLUGIN_SHA>.tar.gz
+<CACHE>/<PLUGIN>/<VERSION>/<OS>-<ARCH>-<PLUGIN_SHA>.json
+```
+
+A cached install is only used if the plugin is a clean git checkout at the same commit, and the
+os, arch and install path match the machine it was built on. The install path must match because
+compiled runtimes often have it built in, so use the same `RTX_DATA_DIR` on every machine. The
+tarball is verified against the sha256 in the metadata before it is unpacked.
+
+```sh-session
+$ export RTX_BINARY_CACHE=/mnt/rtx-cache
+$ rtx install python@3.11.1   # builds it since it is not cached yet
+$ rtx cache push python@3.11.1
+$ ssh other-machine rtx install python@3.11.1   # unpacked from the cache
+```
+
 ## Development
 
 Run tests with `just`:
@@ -0,0 +1,262 @@
+use std::sync::Arc;
+
+use atty::Stream;
+use color_eyre::eyre::{eyre, Result};
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::args::runtime::{RuntimeArg, RuntimeArgParser, RuntimeArgVersion};
+use crate::cli::command::Command;
+use crate::config::plugin_source::PluginSource;
+use crate::config::{alias_chain, parse_config_file, runtime_version_env_key, Config};
+use crate::errors::Error::PluginNotInstalled;
+use crate::output::Output;
+use crate::plugins::Plugin;
+use crate::ui::color::Color;
+
+/// shows the version a runtime resolves to
+///
+/// With only a plugin, this resolves the versions set for it in the config files or in
+/// RTX_<PLUGIN>_VERSION. With @<SPEC>, <SPEC> is resolved the same way as a version in a
+/// config file: aliases first, then the newest installed version starting with it, then
+/// the newest remote version matching it.
+///
+/// Use --explain to see each of these steps.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct Resolve {
+    /// runtime to resolve
+    ///
+    /// e.g.: nodejs or nodejs@18
+    #[clap(value_parser = RuntimeArgParser)]
+    runtime: RuntimeArg,
+
+    /// show how each version was resolved
+    #[clap(long)]
+    explain: bool,
+}
+
+impl Command for Resolve {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let name = &self.runtime.plugin;
+        let plugin = match config.ts.find_plugin(name) {
+            Some(plugin) if plugin.is_installed() => plugin,
+            _ => Err(PluginNotInstalled(name.clone()))?,
+        };
+        let (specs, source) = match &self.runtime.version {
+            RuntimeArgVersion::Version(v) => (vec![v.clone()], "the command line".to_string()),
+            RuntimeArgVersion::System => (vec!["system".into()], "the command line".to_string()),
+            RuntimeArgVersion::None => match config.ts.get_source_for_plugin(name) {
+                Some(source) => (source_specs(&config, name, &source)?, source.to_string()),
+                None => Err(eyre!(
+                    "no version of {name} is set in a config file or {}",
+                    runtime_version_env_key(name)
+                ))?,
+            },
+        };
+        for spec in specs {
+            let mut steps = vec![];
+            let version = resolve(&config, &plugin, &spec, &mut steps)?;
+            if self.explain {
+                rtxprintln!(out, "{name}@{spec} from {source}");
+                for step in steps {
+                    rtxprintln!(out, "  {step}");
+                }
+            } else {
+                rtxprintln!(out, "{version}");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// the unresolved versions for the plugin in the file or env var they came from
+fn source_specs(config: &Config, plugin: &str, source: &PluginSource) -> Result<Vec<String>> {
+    match source {
+        PluginSource::ToolVersions(path)
+        | PluginSource::RtxRc(path)
+        | PluginSource::LegacyVersionFile(path) => {
+            let cf = parse_config_file(path, &config.legacy_filenames, &config.ts)?;
+            Ok(cf.plugins().remove(plugin).unwrap_or_default())
+        }
+        PluginSource::Environment(_, v) => Ok(vec![v.clone()]),
+        PluginSource::Argument(arg) => Ok(vec![arg.version.to_string()]),
+    }
+}
+
+/// resolves `spec` like `Toolset::resolve_all_versions`, describing each step in `steps`
+fn resolve(
+    config: &Config,
+    plugin: &Arc<Plugin>,
+    spec: &str,
+    steps: &mut Vec<String>,
+) -> Result<String> {
+    if spec == "system" {
+        steps.push("system uses the runtime on PATH outside of rtx".into());
+        return Ok(spec.into());
+    }
+
+    let chain = alias_chain(&config.aliases, &plugin.name, spec)?;
+    let plugin_aliases = plugin.list_aliases()?;
+    if chain.len() == 1 {
+        steps.push(format!(
+            "{spec} is not an alias in [alias] or bin/list-aliases"
+        ));
+    }
+    for (from, to) in chain.iter().zip(chain.iter().skip(1)) {
+        let alias_source = match plugin_aliases.contains(&(from.clone(), to.clone())) {
+            true => "bin/list-aliases",
+            false => "[alias]",
+        };
+        steps.push(format!("alias {from} -> {to} ({alias_source})"));
+    }
+    let spec = chain.last().unwrap();
+
+    let installed = plugin.list_installed_versions()?;
+    if let Some(rtv) = config.ts.resolve_version(&plugin.name, spec) {
+        let version = rtv.version.clone();
+        if &version == spec {
+            steps.push(format!("{spec} is installed"));
+        } else {
+            steps.push(format!(
+                "{spec} matched the newest installed version starting with it, out of: {}",
+                summarize(&installed)
+            ));
+            if !is_version_boundary(&version, spec) {
+                steps.push(format!(
+                    "note: {spec} matched {version} because versions are matched by prefix"
+                ));
+            }
+        }
+        steps.push(format!("resolved to {version} (installed)"));
+        return Ok(version);
+    }
+    match installed.is_empty() {
+        true => steps.push("no versions are installed".into()),
+        false => steps.push(format!(
+            "no installed version starts with {spec}, installed: {}",
+            summarize(&installed)
+        )),
+    }
+
+    let latest = plugin.find_latest_version(spec)?;
+    steps.push(format!(
+        "{} remote versions from bin/list-all",
+        latest.versions.len()
+    ));
+    if !latest.excluded.is_empty() {
+        steps.push(format!(
+            "{} removed as pre-releases by VERSION_REGEX: {}",
+            latest.excluded.len(),
+            summarize(&latest.excluded)
+        ));
+    }
+    match latest.matches.is_empty() {
+        true => steps.push(format!("none match {}", latest.query_regex)),
+        false => steps.push(format!(
+            "{} match {}: {}",
+            latest.matches.len(),
+            latest.query_regex,
+            summarize(&latest.matches)
+        )),
+    }
+    let version = match latest.version {
+        Some(version) => {
+            match latest.matches.last() == Some(&version) {
+                true => steps.push(format!("picked {version}, the last match")),
+                false => steps.push(format!("picked {version}, an exact match")),
+            }
+            if !is_version_boundary(&version, spec) {
+                steps.push(format!(
+                    "note: {spec} matched {version} because versions are matched by prefix"
+                ));
+            }
+            version
+        }
+        None => {
+            steps.push(format!("nothing matched, using {spec} as is"));
+            spec.clone()
+        }
+    };
+    let rtv = config.ts.resolve_version(&plugin.name, &version);
+    match rtv.filter(|rtv| rtv.version == version) {
+        Some(_) => steps.push(format!("resolved to {version} (installed)")),
+        None => steps.push(format!(
+            "resolved to {version} (not installed, run `rtx install {}@{version}`)",
+            plugin.name
+        )),
+    }
+    Ok(version)
+}
+
+/// true unless `prefix` stops partway through a number in `version`, e.g.: 3.1 in 3.10.2
+fn is_version_boundary(version: &str, prefix: &str) -> bool {
+    match version.strip_prefix(prefix) {
+        Some(rest) => !rest.starts_with(|c: char| c.is_ascii_digit()),
+        None => true,
+    }
+}
+
+/// the first and last few versions of a long list
+fn summarize(versions: &[String]) -> String {
+    match versions.len() {
+        0..=10 => versions.join(", "),
+        n => format!(
+            "{}, ... {} more ..., {}",
+            versions[..3].join(", "),
+            n - 8,
+            versions[n - 5..].join(", ")
+        ),
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx resolve python
+      3.11.1
+
+      $ rtx resolve python@3.1 --explain
+      python@3.1 from the command line
+        3.1 is not an alias in [alias] or bin/list-aliases
+        no installed version starts with 3.1, installed: 3.9.16, 3.11.1
+        503 remote versions from bin/list-all
+        232 removed as pre-releases by VERSION_REGEX: 2.0.1-dev, ... 224 more ..., stackless-dev
+        13 match ^\s*3.1: 3.1.0, 3.1.1, 3.1.2, ... 5 more ..., 3.10.9
+        picked 3.10.9, the last match
+        note: 3.1 matched 3.10.9 because versions are matched by prefix
+        resolved to 3.10.9 (not installed, run `rtx install python@3.10.9`)
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+
+    use crate::assert_cli;
+
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        assert_cli!("install", "tiny@1");
+        let stdout = assert_cli!("resolve", "tiny@1");
+        assert_display_snapshot!(stdout, @"1.0.1");
+    }
+
+    #[test]
+    fn test_resolve_explain() {
+        assert_cli!("install", "tiny@1");
+        let stdout = assert_cli!("resolve", "tiny@1", "--explain");
+        assert!(stdout.starts_with("tiny@1 from the command line\n"));
+        assert!(stdout.contains("  resolved to 1.0.1 (installed)\n"));
+    }
+
+    #[test]
+    fn test_is_version_boundary() {
+        assert!(is_version_boundary("3.1.4", "3.1"));
+        assert!(is_version_boundary("3.1", "3.1"));
+        assert!(!is_version_boundary("3.10.2", "3.1"));
+    }
+}
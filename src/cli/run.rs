@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+use std::thread;
+
+use atty::Stream;
+use color_eyre::eyre::{eyre, Result};
+use indexmap::{IndexMap, IndexSet};
+use indoc::formatdoc;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::task::Task;
+use crate::config::Config;
+use crate::errors::Error::ScriptFailed;
+use crate::output::Output;
+use crate::ui::color::Color;
+use crate::{cmd, env};
+
+/// run a task defined in a config file
+///
+/// Tasks are defined in the [tasks] section of `.rtxrc.toml`. They run with the same
+/// environment as `rtx exec`, so the runtimes from `.tool-versions` and the [env] section
+/// are available.
+///
+/// Dependencies of a task run first. Dependencies that don't depend on each other run in
+/// parallel.
+///
+/// Any arguments after "--" are appended to the command of the last task.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct Run {
+    /// task(s) to run
+    #[clap(required = true)]
+    task: Vec<String>,
+
+    /// arguments to pass to the last task
+    #[clap(last = true)]
+    args: Vec<String>,
+}
+
+impl Command for Run {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        config.ensure_installed()?;
+        let mut tasks = IndexMap::new();
+        for name in &self.task {
+            resolve_task(&config, name, &mut vec![], &mut tasks)?;
+        }
+        if let Some(last) = self.task.last() {
+            if !self.args.is_empty() {
+                let task = tasks.get_mut(last).unwrap();
+                task.run = format!(
+                    "{} {}",
+                    task.run,
+                    self.args
+                        .iter()
+                        .map(|a| shell_escape::escape(a.into()))
+                        .join(" ")
+                );
+            }
+        }
+
+        let mut env = config.env()?;
+        env.insert("PATH".into(), config.path_env()?);
+
+        let mut done = HashSet::new();
+        let mut pending = tasks.keys().cloned().collect::<IndexSet<_>>();
+        while !pending.is_empty() {
+            let ready = pending
+                .iter()
+                .map(|name| &tasks[name])
+                .filter(|t| t.depends.iter().all(|d| done.contains(d)))
+                .collect_vec();
+            for task in &ready {
+                rtxstatusln!(out, "[{}] $ {}", task.name, task.run);
+            }
+            // tasks spend their time waiting on subprocesses so use a thread per task
+            // rather than the rayon pool which is sized by the number of cpus
+            thread::scope(|s| {
+                ready
+                    .iter()
+                    .map(|task| s.spawn(|| run_task(task, &env, &config.env_remove)))
+                    .collect_vec()
+                    .into_iter()
+                    .map(|h| h.join().unwrap())
+                    .collect::<Result<Vec<()>>>()
+            })?;
+            for task in ready {
+                pending.remove(&task.name);
+                done.insert(task.name.clone());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// adds the task and its dependencies to `tasks` with dependencies first
+fn resolve_task(
+    config: &Config,
+    name: &str,
+    stack: &mut Vec<String>,
+    tasks: &mut IndexMap<String, Task>,
+) -> Result<()> {
+    if stack.iter().any(|n| n == name) {
+        stack.push(name.into());
+        return Err(eyre!("circular task dependency: {}", stack.join(" -> ")));
+    }
+    if tasks.contains_key(name) {
+        return Ok(());
+    }
+    let task = config
+        .tasks
+        .get(name)
+        .ok_or_else(|| eyre!("task not found: {name}"))?;
+    stack.push(name.into());
+    for dep in &task.depends {
+        resolve_task(config, dep, stack, tasks)?;
+    }
+    stack.pop();
+    tasks.insert(name.into(), task.clone());
+    Ok(())
+}
+
+fn run_task(task: &Task, env: &IndexMap<String, String>, env_remove: &[String]) -> Result<()> {
+    if task.run.is_empty() {
+        return Ok(());
+    }
+    let mut cmd = cmd!(&*env::SHELL, "-c", &task.run).dir(task.cwd());
+    for (k, v) in env.iter().chain(task.env.iter()) {
+        cmd = cmd.env(k, v);
+    }
+    for k in env_remove {
+        cmd = cmd.env_remove(k);
+    }
+    let res = cmd.unchecked().run()?;
+    match res.status.success() {
+        true => Ok(()),
+        false => Err(ScriptFailed(task.name.clone(), Some(res.status)))?,
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      # .rtxrc.toml
+      [tasks]
+      lint = "eslint ."
+
+      [tasks.test]
+      run = "npm test"
+      description = "run the tests"
+      depends = ["lint"]
+      dir = "web"
+      env = {{ NODE_ENV = "test" }}
+
+      $ rtx run test
+      $ rtx run test -- --watch
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use indexmap::IndexMap;
+
+    use crate::config::task::Task;
+    use crate::config::Config;
+
+    use super::resolve_task;
+
+    fn task(name: &str, depends: &[&str]) -> (String, Task) {
+        (
+            name.into(),
+            Task {
+                name: name.into(),
+                run: format!("echo {name}"),
+                depends: depends.iter().map(|d| d.to_string()).collect(),
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn test_resolve_task() {
+        let config = Config {
+            tasks: IndexMap::from([
+                task("lint", &[]),
+                task("build", &[]),
+                task("test", &["lint", "build"]),
+            ]),
+            ..Default::default()
+        };
+        let mut tasks = IndexMap::new();
+        resolve_task(&config, "test", &mut vec![], &mut tasks).unwrap();
+        assert_eq!(
+            tasks.keys().collect::<Vec<_>>(),
+            vec!["lint", "build", "test"]
+        );
+    }
+
+    #[test]
+    fn test_resolve_task_circular() {
+        let config = Config {
+            tasks: IndexMap::from([task("a", &["b"]), task("b", &["a"])]),
+            ..Default::default()
+        };
+        let err = resolve_task(&config, "a", &mut vec![], &mut IndexMap::new()).unwrap_err();
+        assert_eq!(err.to_string(), "circular task dependency: a -> b -> a");
+    }
+}
@@ -24,7 +24,10 @@ pub struct SettingsGet {
 impl Command for SettingsGet {
     fn run(self, config: Config, out: &mut Output) -> Result<()> {
         match config.settings.to_index_map().get(&self.key) {
-            Some(value) => Ok(rtxprintln!(out, "{}", value)),
+            Some(value) => {
+                rtxprintln!(out, "{}", value);
+                Ok(())
+            }
             None => Err(eyre!("Unknown setting: {}", self.key)),
         }
     }
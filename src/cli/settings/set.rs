@@ -30,10 +30,11 @@ impl Command for SettingsSet {
             "legacy_version_file" => parse_bool(&self.value)?,
             "plugin_autoupdate_last_check_duration" => parse_i64(&self.value)?,
             "verbose" => parse_bool(&self.value)?,
+            "binary_cache" => self.value.into(),
             _ => return Err(eyre!("Unknown setting: {}", self.key)),
         };
 
-        rtxrc.update_setting(&self.key, value);
+        rtxrc.update_setting(&self.key, value)?;
         rtxrc.save()
     }
 }
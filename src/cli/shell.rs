@@ -0,0 +1,114 @@
+use atty::Stream;
+use color_eyre::eyre::{eyre, Result};
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::args::runtime::{RuntimeArg, RuntimeArgParser, RuntimeArgVersion};
+use crate::cli::command::Command;
+use crate::config::{runtime_version_env_key, Config};
+use crate::errors::Error::VersionNotInstalled;
+use crate::output::Output;
+use crate::runtimes::RuntimeVersion;
+use crate::shell::{get_shell, ShellType};
+use crate::ui::color::Color;
+
+/// sets a runtime version for the current shell session
+///
+/// Sets RTX_<PLUGIN>_VERSION which takes precedence over the versions in config files.
+/// Dashes in the plugin name become underscores, e.g.: RTX_NODE_BUILD_VERSION.
+/// The version is resolved through aliases and installed if missing.
+/// `rtx activate` defines a shell function so this takes effect without `eval`.
+#[derive(Debug, clap::Args)]
+#[clap(visible_alias = "sh", verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct Shell {
+    /// Shell type to generate the commands for
+    #[clap(long, short)]
+    shell: Option<ShellType>,
+
+    /// runtime version(s) to use
+    #[clap(value_parser = RuntimeArgParser, required = true)]
+    runtime: Vec<RuntimeArg>,
+
+    /// removes a previously set version
+    #[clap(long, short)]
+    unset: bool,
+}
+
+impl Command for Shell {
+    fn run(self, mut config: Config, out: &mut Output) -> Result<()> {
+        let shell = get_shell(self.shell);
+        for r in &self.runtime {
+            let key = runtime_version_env_key(&r.plugin);
+            if self.unset {
+                rtxprint!(out, "{}", shell.unset_env(&key));
+                continue;
+            }
+            if r.version == RuntimeArgVersion::None {
+                return Err(eyre!("no version specified for {}", r.plugin));
+            }
+            let version = config
+                .resolve_runtime_arg(r)?
+                .unwrap_or_else(|| "system".into());
+            let plugin = config.ts.get_or_add_plugin(r.plugin.clone())?;
+            let rtv = RuntimeVersion::new(plugin, &version);
+            if !rtv.ensure_installed(&config)? {
+                return Err(VersionNotInstalled(rtv.plugin.name.clone(), rtv.version).into());
+            }
+            rtxprint!(out, "{}", shell.set_env(&key, &version));
+        }
+        Ok(())
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx shell nodejs@18
+      $ node -v
+      v18.15.0
+
+      # without `rtx activate` the output needs to be evaluated
+      $ eval "$(rtx shell nodejs@18)"
+
+      # go back to the version in the config files
+      $ rtx shell --unset nodejs
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+
+    use crate::{assert_cli, assert_cli_err};
+
+    #[test]
+    fn test_shell() {
+        let stdout = assert_cli!("shell", "-s", "bash", "tiny@1");
+        assert_display_snapshot!(stdout, @r###"
+        export RTX_TINY_VERSION=1.0.1
+        "###);
+    }
+
+    #[test]
+    fn test_shell_system() {
+        let stdout = assert_cli!("shell", "-s", "bash", "notinstalled@system");
+        assert_display_snapshot!(stdout, @r###"
+        export RTX_NOTINSTALLED_VERSION=system
+        "###);
+    }
+
+    #[test]
+    fn test_shell_unset() {
+        let stdout = assert_cli!("shell", "-s", "fish", "--unset", "tiny");
+        assert_display_snapshot!(stdout, @r###"
+        set -e RTX_TINY_VERSION
+        "###);
+    }
+
+    #[test]
+    fn test_shell_no_version() {
+        let err = assert_cli_err!("shell", "-s", "bash", "tiny");
+        assert_display_snapshot!(err, @"no version specified for tiny");
+    }
+}
@@ -0,0 +1,43 @@
+use atty::Stream;
+use color_eyre::eyre::Result;
+use indoc::formatdoc;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::file::display_path;
+use crate::output::Output;
+use crate::ui::color::Color;
+
+/// list the tasks that can be run with `rtx run`
+///
+/// Tasks are defined in the [tasks] section of `.rtxrc.toml` files.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct Tasks {}
+
+impl Command for Tasks {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        for task in config.tasks.values().sorted_by_key(|t| &t.name) {
+            rtxprintln!(
+                out,
+                "{:20} {:40} {}",
+                task.name,
+                task.description.as_deref().unwrap_or(&task.run),
+                display_path(&task.config_path)
+            );
+        }
+        Ok(())
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx tasks
+      lint                 eslint .                                 ~/src/myproj/.rtxrc.toml
+      test                 run the tests                            ~/src/myproj/.rtxrc.toml
+    "#, COLOR.header("Examples:")}
+});
@@ -0,0 +1,84 @@
+use std::path::PathBuf;
+
+use atty::Stream;
+use color_eyre::eyre::{eyre, Result};
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::{trust, Config};
+use crate::dirs;
+use crate::file::display_path;
+use crate::output::Output;
+use crate::ui::color::Color;
+
+/// marks a config file as trusted
+///
+/// Project config files like `.rtxrc.toml` can set env vars, PATH entries and tasks.
+/// Until they are trusted, only the runtime versions in them are used.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct Trust {
+    /// the config file or directory to trust
+    /// defaults to the config files in the current directory
+    #[clap(value_hint = clap::ValueHint::AnyPath, verbatim_doc_comment)]
+    path: Option<PathBuf>,
+
+    /// only trust the current contents of the file
+    /// it will need to be trusted again after it is modified
+    #[clap(long, verbatim_doc_comment)]
+    hash: bool,
+}
+
+impl Command for Trust {
+    fn run(self, _config: Config, out: &mut Output) -> Result<()> {
+        for path in config_files(self.path)? {
+            trust::trust(&path, self.hash)?;
+            rtxprintln!(out, "trusted {}", display_path(&path));
+        }
+        Ok(())
+    }
+}
+
+/// `path` if it is a file, otherwise the config files in the directory
+pub fn config_files(path: Option<PathBuf>) -> Result<Vec<PathBuf>> {
+    let path = path.unwrap_or_else(|| dirs::CURRENT.clone());
+    if path.is_file() {
+        return Ok(vec![path]);
+    }
+    let files = trust::config_files_in(&path)?;
+    if files.is_empty() {
+        return Err(eyre!(
+            "no rtx config files found in {}",
+            display_path(&path)
+        ));
+    }
+    Ok(files)
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      # trusts the config files in the current directory
+      $ rtx trust
+      trusted ~/src/myproj/.rtxrc.toml
+
+      # trusts a specific file until it is modified
+      $ rtx trust --hash ~/src/myproj/.rtx.local.toml
+      trusted ~/src/myproj/.rtx.local.toml
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_display_snapshot;
+
+    use crate::assert_cli_err;
+
+    #[test]
+    fn test_trust_no_config_files() {
+        let err = assert_cli_err!("trust", "src");
+        assert_display_snapshot!(err, @"no rtx config files found in src");
+    }
+}
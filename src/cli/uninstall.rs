@@ -33,7 +33,7 @@ impl Command for Uninstall {
                 let prefix = match &a.version {
                     RuntimeArgVersion::None => config.resolve_runtime_arg(a)?.unwrap(),
                     RuntimeArgVersion::Version(version) => {
-                        config.resolve_alias(&a.plugin, version.to_string())
+                        config.resolve_alias(&a.plugin, version.to_string())?
                     }
                     _ => Err(eyre!("invalid version {}", a.to_string()))?,
                 };
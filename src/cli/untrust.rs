@@ -0,0 +1,47 @@
+use std::path::PathBuf;
+
+use atty::Stream;
+use color_eyre::eyre::Result;
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::cli::trust::config_files;
+use crate::config::{trust, Config};
+use crate::file::display_path;
+use crate::output::Output;
+use crate::ui::color::Color;
+
+/// removes trust from a config file
+///
+/// Only the runtime versions in the file will be used until it is trusted again.
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct Untrust {
+    /// the config file or directory to untrust
+    /// defaults to the config files in the current directory
+    #[clap(value_hint = clap::ValueHint::AnyPath, verbatim_doc_comment)]
+    path: Option<PathBuf>,
+}
+
+impl Command for Untrust {
+    fn run(self, _config: Config, out: &mut Output) -> Result<()> {
+        for path in config_files(self.path)? {
+            if trust::untrust(&path)? {
+                rtxprintln!(out, "untrusted {}", display_path(&path));
+            } else {
+                warn!("{} is not trusted", display_path(&path));
+            }
+        }
+        Ok(())
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx untrust
+      untrusted ~/src/myproj/.rtxrc.toml
+    "#, COLOR.header("Examples:")}
+});
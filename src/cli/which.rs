@@ -0,0 +1,158 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use atty::Stream;
+use color_eyre::eyre::{eyre, Result};
+use indoc::formatdoc;
+use once_cell::sync::Lazy;
+
+use crate::cli::command::Command;
+use crate::config::Config;
+use crate::output::Output;
+use crate::runtimes::RuntimeVersion;
+use crate::ui::color::Color;
+use crate::{env, file};
+
+/// shows the path that a bin name points to
+///
+/// Searches the bin paths of the active runtimes in the same order they are added to PATH.
+/// Plugins with a `bin/exec-path` script can point a bin to a different executable.
+/// If no runtime provides the bin, the PATH outside of rtx is searched and the plugin and
+/// version are shown as "system".
+#[derive(Debug, clap::Args)]
+#[clap(verbatim_doc_comment, after_long_help = AFTER_LONG_HELP.as_str())]
+pub struct Which {
+    /// the bin name to look up
+    #[clap()]
+    bin_name: String,
+
+    /// show the plugin name instead of the path
+    #[clap(long, conflicts_with = "version")]
+    plugin: bool,
+
+    /// show the version instead of the path
+    #[clap(long, conflicts_with = "plugin")]
+    version: bool,
+
+    /// show every installed runtime version that provides the bin, not only the active ones
+    #[clap(long, short)]
+    all: bool,
+}
+
+impl Command for Which {
+    fn run(self, config: Config, out: &mut Output) -> Result<()> {
+        let found = match self.all {
+            true => self.find_all(&config)?,
+            false => self.find_active(&config)?.into_iter().collect(),
+        };
+        if found.is_empty() {
+            return Err(eyre!("{} not found", self.bin_name));
+        }
+        for (rtv, path) in found {
+            match (rtv, self.plugin, self.version) {
+                (Some(rtv), true, _) => rtxprintln!(out, "{}", rtv.plugin.name),
+                (Some(rtv), _, true) => rtxprintln!(out, "{}", rtv.version),
+                (None, true, _) | (None, _, true) => rtxprintln!(out, "system"),
+                _ => rtxprintln!(out, "{}", path.display()),
+            }
+        }
+        Ok(())
+    }
+}
+
+type Found = (Option<Arc<RuntimeVersion>>, PathBuf);
+
+impl Which {
+    fn find_active(&self, config: &Config) -> Result<Option<Found>> {
+        for rtv in config.ts.list_current_installed_versions() {
+            if let Some(path) = self.find_in_runtime(&rtv)? {
+                return Ok(Some((Some(rtv), path)));
+            }
+        }
+        Ok(env::PATH
+            .iter()
+            .map(|p| p.join(&self.bin_name))
+            .find(|p| file::is_executable(p))
+            .map(|path| (None, path)))
+    }
+
+    fn find_all(&self, config: &Config) -> Result<Vec<Found>> {
+        let mut found = vec![];
+        for rtv in config.ts.list_installed_versions() {
+            if let Some(path) = self.find_in_runtime(&rtv)? {
+                found.push((Some(rtv), path));
+            }
+        }
+        Ok(found)
+    }
+
+    fn find_in_runtime(&self, rtv: &RuntimeVersion) -> Result<Option<PathBuf>> {
+        let path = rtv
+            .list_bin_paths()?
+            .into_iter()
+            .map(|p| p.join(&self.bin_name))
+            .find(|p| file::is_executable(p));
+        match path {
+            Some(path) => Ok(Some(rtv.exec_path(&self.bin_name, path)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stream::Stdout));
+static AFTER_LONG_HELP: Lazy<String> = Lazy::new(|| {
+    formatdoc! {r#"
+    {}
+      $ rtx which node
+      /home/username/.local/share/rtx/installs/nodejs/20.0.0/bin/node
+      $ rtx which node --plugin
+      nodejs
+      $ rtx which node --version
+      20.0.0
+      $ rtx which node --all --version
+      18.0.0
+      20.0.0
+    "#, COLOR.header("Examples:")}
+});
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+
+    use crate::{assert_cli, assert_cli_err, dirs};
+
+    #[test]
+    fn test_which() {
+        assert_cli!("plugin", "add", "shfmt");
+        assert_cli!("install");
+        let stdout = assert_cli!("which", "shfmt");
+        assert_str_eq!(
+            stdout.trim(),
+            dirs::ROOT
+                .join("installs/shfmt/3.5.1/bin/shfmt")
+                .to_string_lossy()
+        );
+    }
+
+    #[test]
+    fn test_which_plugin() {
+        assert_cli!("plugin", "add", "shfmt");
+        assert_cli!("install");
+        let stdout = assert_cli!("which", "--plugin", "shfmt");
+        assert_str_eq!(stdout.trim(), "shfmt");
+        let stdout = assert_cli!("which", "--version", "shfmt");
+        assert_str_eq!(stdout.trim(), "3.5.1");
+    }
+
+    #[test]
+    fn test_which_system() {
+        let stdout = assert_cli!("which", "--version", "sh");
+        assert_str_eq!(stdout.trim(), "system");
+    }
+
+    #[test]
+    fn test_which_not_found() {
+        let err = assert_cli_err!("which", "rtx-not-a-real-bin");
+        assert_str_eq!(err.to_string(), "rtx-not-a-real-bin not found");
+    }
+}
@@ -72,8 +72,6 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::cmd;
-
     #[test]
     fn test_cmd() {
         let output = cmd!("echo", "foo", "bar").read().unwrap();
@@ -0,0 +1,62 @@
+use std::fs;
+use std::path::Path;
+
+use color_eyre::eyre::{eyre, Result};
+
+use crate::config::settings::SettingsBuilder;
+
+/// reads the settings rtx shares with asdf from an `.asdfrc` file so rtx can be dropped in
+/// where asdf was used. Settings in rtx's own config.toml take precedence over these.
+pub fn load(path: &Path) -> Result<SettingsBuilder> {
+    let mut settings = SettingsBuilder::default();
+    if !path.is_file() {
+        return Ok(settings);
+    }
+    let contents = fs::read_to_string(path)?;
+    for line in contents.lines() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        let Some((k, v)) = line.split_once('=') else {
+            continue;
+        };
+        let k = k.trim();
+        let parse_bool = || match v.trim() {
+            "yes" => Ok(true),
+            "no" => Ok(false),
+            v => Err(eyre!(
+                "invalid {k} in {}: expected yes or no, got: {v}",
+                path.display()
+            )),
+        };
+        match k {
+            "legacy_version_file" => settings.legacy_version_file = Some(parse_bool()?),
+            "always_keep_download" => settings.always_keep_download = Some(parse_bool()?),
+            k => trace!("ignoring unsupported .asdfrc setting: {}", k),
+        }
+    }
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".asdfrc");
+        fs::write(
+            &path,
+            "# asdf settings\nlegacy_version_file = yes\nalways_keep_download=no\nconcurrency = auto\n",
+        )
+        .unwrap();
+        let settings = load(&path).unwrap();
+        assert_eq!(settings.legacy_version_file, Some(true));
+        assert_eq!(settings.always_keep_download, Some(false));
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let settings = load(Path::new("/does/not/exist/.asdfrc")).unwrap();
+        assert_eq!(settings.legacy_version_file, None);
+    }
+}
@@ -6,7 +6,8 @@ use color_eyre::eyre::Result;
 use indexmap::IndexMap;
 
 use crate::config::config_file::{ConfigFile, ConfigFileType};
-use crate::config::PluginSource;
+use crate::config::task::Task;
+use crate::config::{AliasMap, PluginSource};
 use crate::plugins::{Plugin, PluginName};
 
 #[derive(Debug)]
@@ -53,6 +54,30 @@ impl ConfigFile for LegacyVersionFile {
         HashMap::new()
     }
 
+    fn aliases(&self) -> AliasMap {
+        AliasMap::new()
+    }
+
+    fn env_remove(&self) -> Vec<String> {
+        vec![]
+    }
+
+    fn env_files(&self) -> Vec<PathBuf> {
+        vec![]
+    }
+
+    fn tasks(&self) -> Vec<Task> {
+        vec![]
+    }
+
+    fn env_path(&self) -> Vec<PathBuf> {
+        vec![]
+    }
+
+    fn env_path_after(&self) -> Vec<PathBuf> {
+        vec![]
+    }
+
     fn remove_plugin(&mut self, _plugin_name: &PluginName) {
         unimplemented!()
     }
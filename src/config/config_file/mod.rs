@@ -1,6 +1,6 @@
 use std::collections::HashMap;
-use std::fmt::{Debug, Display};
-use std::path::Path;
+use std::fmt::{Debug, Display, Formatter};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use color_eyre::eyre::{eyre, Result};
@@ -10,8 +10,8 @@ use rtxrc::RTXFile;
 use tool_versions::ToolVersions;
 
 use crate::cli::args::runtime::{RuntimeArg, RuntimeArgVersion};
-use crate::config::Config;
-use crate::config::PluginSource;
+use crate::config::task::Task;
+use crate::config::{AliasMap, Config, PluginSource};
 use crate::env;
 use crate::errors::Error::VersionNotInstalled;
 use crate::file::display_path;
@@ -30,12 +30,32 @@ pub enum ConfigFileType {
     LegacyVersion,
 }
 
+impl Display for ConfigFileType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigFileType::RtxRc => write!(f, "rtxrc"),
+            ConfigFileType::ToolVersions => write!(f, "tool-versions"),
+            ConfigFileType::LegacyVersion => write!(f, "legacy-version"),
+        }
+    }
+}
+
 pub trait ConfigFile: Debug + Display + Send {
     fn get_type(&self) -> ConfigFileType;
     fn get_path(&self) -> &Path;
     fn source(&self) -> PluginSource;
     fn plugins(&self) -> IndexMap<PluginName, Vec<String>>;
     fn env(&self) -> HashMap<String, String>;
+    fn aliases(&self) -> AliasMap;
+    /// env vars to unset
+    fn env_remove(&self) -> Vec<String>;
+    /// dotenv files to load into the environment
+    fn env_files(&self) -> Vec<PathBuf>;
+    fn tasks(&self) -> Vec<Task>;
+    /// directories to add to PATH ahead of the runtime bin paths
+    fn env_path(&self) -> Vec<PathBuf>;
+    /// directories to add to PATH after the runtime bin paths
+    fn env_path_after(&self) -> Vec<PathBuf>;
     fn remove_plugin(&mut self, plugin_name: &PluginName);
     fn add_version(&mut self, plugin_name: &PluginName, version: &str);
     fn replace_versions(&mut self, plugin_name: &PluginName, versions: &[String]);
@@ -110,7 +130,7 @@ impl dyn ConfigFile {
 }
 
 pub fn init(path: &Path) -> Box<dyn ConfigFile> {
-    if path.ends_with(".rtxrc") || path.ends_with(".rtxrc.toml") {
+    if detect_config_file_type(path) == Some(ConfigFileType::RtxRc) {
         return Box::new(RTXFile::init(path));
     } else if path.ends_with(env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str()) {
         return Box::new(ToolVersions::init(path));
@@ -128,9 +148,11 @@ pub fn parse(path: &Path) -> Result<Box<dyn ConfigFile>> {
     }
 }
 
-fn detect_config_file_type(path: &Path) -> Option<ConfigFileType> {
+pub fn detect_config_file_type(path: &Path) -> Option<ConfigFileType> {
     match path.file_name().unwrap().to_str().unwrap() {
         ".rtxrc" | ".rtxrc.toml" | "config.toml" => Some(ConfigFileType::RtxRc),
+        // .rtx.local.toml and RTX_ENV profiles like .rtx.staging.toml
+        f if f.starts_with(".rtx.") && f.ends_with(".toml") => Some(ConfigFileType::RtxRc),
         f if env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str() == f => {
             Some(ConfigFileType::ToolVersions)
         }
@@ -152,6 +174,14 @@ mod tests {
             detect_config_file_type(Path::new("/foo/bar/.rtxrc.toml")),
             Some(ConfigFileType::RtxRc)
         );
+        assert_eq!(
+            detect_config_file_type(Path::new("/foo/bar/.rtx.local.toml")),
+            Some(ConfigFileType::RtxRc)
+        );
+        assert_eq!(
+            detect_config_file_type(Path::new("/foo/bar/.rtx.staging.toml")),
+            Some(ConfigFileType::RtxRc)
+        );
         assert_eq!(
             detect_config_file_type(Path::new("/foo/bar/.tool-versions")),
             Some(ConfigFileType::ToolVersions)
@@ -7,17 +7,35 @@ use std::time::Duration;
 use color_eyre::eyre::{eyre, Context};
 use color_eyre::{Result, Section, SectionExt};
 use indexmap::IndexMap;
+use itertools::Itertools;
 use toml::Value;
+use toml_edit::TableLike;
 
 use crate::config::config_file::{ConfigFile, ConfigFileType};
+use crate::config::schema;
 use crate::config::settings::{MissingRuntimeBehavior, Settings, SettingsBuilder};
+use crate::config::task::Task;
 use crate::config::AliasMap;
 use crate::config::PluginSource;
+use crate::env;
+use crate::errors::Error::InvalidConfig;
+use crate::file::display_path;
 use crate::plugins::PluginName;
 
 const ENV_SUGGESTION: &str = r#"
 [env]
 FOO = "bar"
+BAR = false # unsets BAR
+"#;
+
+const TASKS_SUGGESTION: &str = r#"
+[tasks]
+lint = "eslint ."
+
+[tasks.test]
+run = "npm test"
+description = "run the tests"
+depends = ["lint"]
 "#;
 
 #[derive(Debug, Default)]
@@ -25,6 +43,11 @@ pub struct RTXFile {
     pub path: PathBuf,
     pub plugins: IndexMap<String, Plugin>,
     pub env: HashMap<String, String>,
+    pub env_remove: Vec<String>,
+    pub env_files: Vec<PathBuf>,
+    pub env_path: Vec<PathBuf>,
+    pub env_path_after: Vec<PathBuf>,
+    pub tasks: IndexMap<String, Task>,
     edit: Option<toml_edit::Document>,
     settings: SettingsBuilder,
 }
@@ -46,7 +69,12 @@ impl RTXFile {
     pub fn from_file(filename: &Path) -> Result<RTXFile> {
         trace!("parsing rtxrc: {}", filename.display());
         let body = fs::read_to_string(filename).suggestion("ensure file exists and can be read")?;
-        let mut rf = RTXFile::from_str(body).wrap_err("error parsing toml")?;
+        if *env::RTX_STRICT {
+            check_strict(filename, &body)
+                .wrap_err_with(|| InvalidConfig(filename.to_path_buf()))?;
+        }
+        let mut rf =
+            RTXFile::from_str(body).wrap_err_with(|| InvalidConfig(filename.to_path_buf()))?;
         rf.path = filename.into();
 
         Ok(rf)
@@ -76,9 +104,17 @@ impl RTXFile {
         self.settings.build()
     }
 
+    pub fn settings_builder(&self) -> SettingsBuilder {
+        self.settings.clone()
+    }
+
     fn parse_toplevel_key(&mut self, k: &String, v: &Value) -> Result<()> {
         match k.to_lowercase().as_str() {
             "env" => self.parse_env(v).with_suggestion(|| ENV_SUGGESTION)?,
+            "env_file" => self.env_files = self.parse_env_files(k, v)?,
+            "tasks" => self.parse_tasks(v).with_suggestion(|| TASKS_SUGGESTION)?,
+            "env_path" => self.env_path = self.parse_paths(k, v)?,
+            "env_path_after" => self.env_path_after = self.parse_paths(k, v)?,
             "missing_runtime_behavior" => {
                 self.settings.missing_runtime_behavior =
                     Some(self.parse_missing_runtime_behavior(v)?)
@@ -94,6 +130,7 @@ impl RTXFile {
                     Some(self.parse_duration_minutes(k, v)?)
             }
             "verbose" => self.settings.verbose = Some(self.parse_bool(k, v)?),
+            "binary_cache" => self.settings.binary_cache = Some(self.parse_string(k, v)?),
             "alias" => self.settings.aliases = Some(self.parse_aliases(v)?),
             "get_path" => {}
             "disable_plugin_short_name_repository" => {}
@@ -111,7 +148,12 @@ impl RTXFile {
                         Value::String(s) => {
                             self.env.insert(k.into(), s.into());
                         }
-                        _ => Err(eyre!("expected [env] value to be a string, got: {v}"))?,
+                        Value::Boolean(false) => {
+                            self.env_remove.push(k.into());
+                        }
+                        _ => Err(eyre!(
+                            "expected [env] value to be a string or false, got: {v}"
+                        ))?,
                     }
                 }
                 Ok(())
@@ -120,6 +162,100 @@ impl RTXFile {
         }
     }
 
+    fn parse_tasks(&mut self, v: &Value) -> Result<()> {
+        match v {
+            Value::Table(table) => {
+                for (name, v) in table.iter() {
+                    let task = self
+                        .parse_task(name, v)
+                        .wrap_err_with(|| eyre!("invalid task: {name}"))?;
+                    self.tasks.insert(name.into(), task);
+                }
+                Ok(())
+            }
+            _ => Err(eyre!("expected [tasks] to be a table, got: {v}")),
+        }
+    }
+
+    fn parse_task(&self, name: &str, v: &Value) -> Result<Task> {
+        let mut task = Task {
+            name: name.into(),
+            ..Default::default()
+        };
+        match v {
+            Value::String(s) => task.run = s.into(),
+            Value::Table(table) => {
+                for (k, v) in table.iter() {
+                    match k.as_str() {
+                        "run" => task.run = self.parse_string(k, v)?,
+                        "description" => task.description = Some(self.parse_string(k, v)?),
+                        "depends" => task.depends = self.parse_strings(k, v)?,
+                        "dir" => task.dir = Some(self.parse_string(k, v)?.into()),
+                        "env" => {
+                            for (k, v) in self.parse_string_table(k, v)? {
+                                task.env.insert(k, v);
+                            }
+                        }
+                        _ => Err(eyre!("unknown task key: {k}"))?,
+                    }
+                }
+            }
+            _ => Err(eyre!("expected task to be a string or table, got: {v}"))?,
+        }
+        if task.run.is_empty() && task.depends.is_empty() {
+            return Err(eyre!("expected task to have run or depends"));
+        }
+        Ok(task)
+    }
+
+    fn parse_string_table(&self, k: &str, v: &Value) -> Result<Vec<(String, String)>> {
+        match v {
+            Value::Table(table) => table
+                .iter()
+                .map(|(tk, tv)| Ok((tk.into(), self.parse_string(&format!("{k}.{tk}"), tv)?)))
+                .collect(),
+            _ => Err(eyre!("expected {k} to be a table, got: {v}")),
+        }
+    }
+
+    fn parse_env_files(&self, k: &str, v: &Value) -> Result<Vec<PathBuf>> {
+        match v {
+            Value::String(s) => Ok(vec![PathBuf::from(s)]),
+            Value::Array(_) => self.parse_paths(k, v),
+            _ => Err(eyre!("expected {k} to be a string or array, got: {v}")),
+        }
+    }
+
+    fn parse_paths(&self, k: &str, v: &Value) -> Result<Vec<PathBuf>> {
+        Ok(self
+            .parse_strings(k, v)?
+            .into_iter()
+            .map(PathBuf::from)
+            .collect())
+    }
+
+    fn parse_strings(&self, k: &str, v: &Value) -> Result<Vec<String>> {
+        match v {
+            Value::Array(a) => a
+                .iter()
+                .map(|v| match v {
+                    Value::String(s) => Ok(s.to_string()),
+                    _ => Err(eyre!("expected {k} value to be a string, got: {v}")),
+                })
+                .collect(),
+            _ => Err(eyre!("expected {k} to be an array, got: {v}")),
+        }
+    }
+
+    /// paths are relative to the directory containing the config file
+    fn resolve_paths(&self, paths: &[PathBuf]) -> Vec<PathBuf> {
+        let dir = self.path.parent().unwrap_or(Path::new(""));
+        paths
+            .iter()
+            .map(|p| dir.join(p).components().collect())
+            .collect()
+    }
+
     fn parse_plugin(&mut self, k: &String, v: &Value) -> Result<()> {
         let versions = self.parse_plugin_versions(v)?;
         self.plugins.insert(
@@ -218,75 +354,97 @@ impl RTXFile {
 
     fn get_or_create_edit(&mut self) -> &mut toml_edit::Document {
         if self.edit.is_none() {
-            if !self.path.exists() {
-                let dir = self.path.parent().unwrap();
-                fs::create_dir_all(dir).expect("could not create directory");
-                fs::write(&self.path, "").expect("could not create new config.toml file");
-            }
-            let body = fs::read_to_string(&self.path)
-                .suggestion("ensure file exists and can be read")
-                .unwrap();
-            self.edit = Some(body.parse::<toml_edit::Document>().unwrap());
+            self.edit = Some(self.get_edit().unwrap());
         }
         self.edit.as_mut().unwrap()
     }
 
-    fn get_edit(&self) -> Result<toml_edit::Document> {
+    /// the document to edit, which is empty if the file doesn't exist yet
+    pub fn get_edit(&self) -> Result<toml_edit::Document> {
         match &self.edit {
             Some(doc) => Ok(doc.clone()),
+            None if !self.path.exists() => Ok(toml_edit::Document::new()),
             None => {
                 let body = fs::read_to_string(&self.path)
-                    .suggestion("ensure file exists and can be read")
-                    .unwrap();
+                    .suggestion("ensure file exists and can be read")?;
                 Ok(body.parse::<toml_edit::Document>()?)
             }
         }
     }
 
-    pub fn update_setting<V: Into<toml_edit::Value>>(&mut self, key: &str, value: V) {
-        let doc = self.get_or_create_edit();
-        let key = key.split('.').collect::<Vec<&str>>();
-        let mut table = doc.as_table_mut();
-        for (i, k) in key.iter().enumerate() {
-            if i == key.len() - 1 {
-                table[k] = toml_edit::value(value);
-                break;
-            } else {
-                table = table
-                    .entry(k)
-                    .or_insert(toml_edit::table())
-                    .as_table_mut()
-                    .unwrap();
+    /// the value at a dotted key like "alias.nodejs.lts", if it is set
+    pub fn get_setting(&self, key: &str) -> Result<Option<toml_edit::Item>> {
+        let doc = self.get_edit()?;
+        let mut item = doc.as_item();
+        for k in parse_key(key)? {
+            match item.get(&k) {
+                Some(i) => item = i,
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(item.clone()))
+    }
+
+    /// sets the value at a dotted key, creating tables as needed. The formatting of the
+    /// rest of the file, including comments after an existing value, is kept.
+    pub fn update_setting<V: Into<toml_edit::Value>>(&mut self, key: &str, value: V) -> Result<()> {
+        let key = parse_key(key)?;
+        let (last, parents) = key.split_last().unwrap();
+        let mut table = self.get_or_create_edit().as_table_mut() as &mut dyn TableLike;
+        for (i, k) in parents.iter().enumerate() {
+            if !table.contains_key(k) {
+                table.insert(k, toml_edit::table());
             }
+            table = table
+                .get_mut(k)
+                .and_then(|item| item.as_table_like_mut())
+                .ok_or_else(|| eyre!("{} is not a table", key[..=i].join(".")))?;
         }
+        let mut value = value.into();
+        match table.get_mut(last) {
+            Some(item) => {
+                if let Some(old) = item.as_value() {
+                    *value.decor_mut() = old.decor().clone();
+                }
+                *item = toml_edit::Item::Value(value);
+            }
+            None => {
+                table.insert(last, toml_edit::value(value));
+            }
+        }
+        Ok(())
     }
 
-    pub fn remove_setting(&mut self, key: &str) {
-        let doc = self.get_or_create_edit();
-        let key = key.split('.').collect::<Vec<&str>>();
-        let mut table = doc.as_table_mut();
-        for (i, k) in key.iter().enumerate() {
-            if i == key.len() - 1 {
-                table.remove(k);
-                break;
-            } else {
-                table = table
-                    .entry(k)
-                    .or_insert(toml_edit::table())
-                    .as_table_mut()
-                    .unwrap();
+    /// removes the value at a dotted key, if it is set
+    pub fn remove_setting(&mut self, key: &str) -> Result<()> {
+        let key = parse_key(key)?;
+        let (last, parents) = key.split_last().unwrap();
+        let mut table = self.get_or_create_edit().as_table_mut() as &mut dyn TableLike;
+        for k in parents {
+            match table.get_mut(k).and_then(|item| item.as_table_like_mut()) {
+                Some(t) => table = t,
+                None => return Ok(()),
             }
         }
+        table.remove(last);
+        Ok(())
     }
 
     pub fn set_alias(&mut self, plugin: &str, from: &str, to: &str) {
+        self.settings
+            .aliases
+            .get_or_insert_with(AliasMap::new)
+            .entry(plugin.into())
+            .or_default()
+            .insert(from.into(), to.into());
         let doc = self.get_or_create_edit();
         let aliases = doc
             .as_table_mut()
-            .entry("aliases")
+            .entry("alias")
             .or_insert(toml_edit::table())
             .as_table_mut()
             .unwrap();
+        aliases.set_implicit(true);
         let plugin_aliases = aliases
             .entry(plugin)
             .or_insert(toml_edit::table())
@@ -294,6 +452,47 @@ impl RTXFile {
             .unwrap();
         plugin_aliases[from] = toml_edit::value(to);
     }
+
+    pub fn remove_alias(&mut self, plugin: &str, from: &str) {
+        if let Some(aliases) = self.settings.aliases.as_mut() {
+            if let Some(plugin_aliases) = aliases.get_mut(plugin) {
+                plugin_aliases.remove(from);
+            }
+        }
+        let doc = self.get_or_create_edit();
+        if let Some(aliases) = doc.get_mut("alias").and_then(|v| v.as_table_mut()) {
+            if let Some(plugin_aliases) = aliases.get_mut(plugin).and_then(|v| v.as_table_mut()) {
+                plugin_aliases.remove(from);
+                if plugin_aliases.is_empty() {
+                    aliases.remove(plugin);
+                }
+            }
+            if aliases.is_empty() {
+                doc.remove("alias");
+            }
+        }
+    }
+}
+
+/// splits a dotted key like `alias.nodejs."18.x"` into its parts
+fn parse_key(key: &str) -> Result<Vec<String>> {
+    let keys = toml_edit::Key::parse(key).map_err(|_| eyre!("invalid key: {key}"))?;
+    Ok(keys.iter().map(|k| k.get().to_string()).collect())
+}
+
+/// with RTX_STRICT set, unknown keys and values of the wrong type are errors instead of
+/// unknown keys being treated as plugins
+fn check_strict(filename: &Path, body: &str) -> Result<()> {
+    let problems = schema::validate(body);
+    if problems.is_empty() {
+        return Ok(());
+    }
+    let path = display_path(filename);
+    Err(eyre!(problems
+        .iter()
+        .map(|p| format!("{path}:{p}"))
+        .join("\n")))
+    .suggestion("run `rtx config validate` to check config files")
 }
 
 impl Display for RTXFile {
@@ -326,6 +525,36 @@ impl ConfigFile for RTXFile {
         self.env.clone()
     }
 
+    fn env_remove(&self) -> Vec<String> {
+        self.env_remove.clone()
+    }
+
+    fn aliases(&self) -> AliasMap {
+        self.settings.aliases.clone().unwrap_or_default()
+    }
+
+    fn env_files(&self) -> Vec<PathBuf> {
+        self.resolve_paths(&self.env_files)
+    }
+
+    fn tasks(&self) -> Vec<Task> {
+        self.tasks
+            .values()
+            .map(|task| Task {
+                config_path: self.path.clone(),
+                ..task.clone()
+            })
+            .collect()
+    }
+
+    fn env_path(&self) -> Vec<PathBuf> {
+        self.resolve_paths(&self.env_path)
+    }
+
+    fn env_path_after(&self) -> Vec<PathBuf> {
+        self.resolve_paths(&self.env_path_after)
+    }
+
     fn remove_plugin(&mut self, plugin: &PluginName) {
         self.plugins.remove(plugin);
         self.get_or_create_edit().as_table_mut().remove(plugin);
@@ -362,6 +591,9 @@ impl ConfigFile for RTXFile {
 
     fn save(&self) -> Result<()> {
         let contents = self.dump();
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
         Ok(fs::write(&self.path, contents)?)
     }
 
@@ -460,6 +692,21 @@ foo="bar"
         assert_eq!(cf.env["foo"], "bar");
     }
 
+    #[test]
+    fn test_env_remove() {
+        let cf = RTXFile::from_str(
+            r#"
+[env]
+NODE_OPTIONS = false
+"#
+            .to_string(),
+        )
+        .unwrap();
+
+        assert!(cf.env.is_empty());
+        assert_eq!(cf.env_remove, vec!["NODE_OPTIONS"]);
+    }
+
     #[test]
     fn test_invalid_env() {
         let err = RTXFile::from_str(
@@ -484,7 +731,110 @@ foo=[1,2,3]
         )
         .unwrap_err();
 
-        assert_display_snapshot!(err, @"expected [env] value to be a string, got: [1, 2, 3]");
+        assert_display_snapshot!(err, @"expected [env] value to be a string or false, got: [1, 2, 3]");
+    }
+
+    #[test]
+    fn test_env_file() {
+        let mut cf = RTXFile::from_str(
+            r#"
+env_file = [".env", "/etc/project.env"]
+"#
+            .to_string(),
+        )
+        .unwrap();
+        cf.path = PathBuf::from("/foo/bar/.rtxrc.toml");
+
+        assert_eq!(
+            cf.env_files(),
+            vec![
+                PathBuf::from("/foo/bar/.env"),
+                PathBuf::from("/etc/project.env")
+            ]
+        );
+
+        let cf = RTXFile::from_str(r#"env_file = ".env""#.to_string()).unwrap();
+        assert_eq!(cf.env_files(), vec![PathBuf::from(".env")]);
+    }
+
+    #[test]
+    fn test_tasks() {
+        let cf = RTXFile::from_str(
+            r#"
+[tasks]
+lint = "eslint ."
+
+[tasks.test]
+run = "npm test"
+description = "run the tests"
+depends = ["lint"]
+dir = "web"
+env = { NODE_ENV = "test" }
+"#
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(cf.tasks.len(), 2);
+        assert_eq!(cf.tasks["lint"].run, "eslint .");
+        let test = &cf.tasks["test"];
+        assert_eq!(test.run, "npm test");
+        assert_eq!(test.description, Some("run the tests".into()));
+        assert_eq!(test.depends, vec!["lint"]);
+        assert_eq!(test.dir, Some(PathBuf::from("web")));
+        assert_eq!(test.env["NODE_ENV"], "test");
+    }
+
+    #[test]
+    fn test_invalid_task() {
+        let err = RTXFile::from_str(
+            r#"
+[tasks.test]
+command = "npm test"
+"#
+            .to_string(),
+        )
+        .unwrap_err();
+
+        assert_display_snapshot!(err, @"invalid task: test");
+    }
+
+    #[test]
+    fn test_env_path() {
+        let mut cf = RTXFile::from_str(
+            r#"
+env_path = ["./bin", "/opt/tools/bin"]
+env_path_after = ["node_modules/.bin"]
+"#
+            .to_string(),
+        )
+        .unwrap();
+        cf.path = PathBuf::from("/foo/bar/.rtxrc.toml");
+
+        assert_eq!(
+            cf.env_path(),
+            vec![
+                PathBuf::from("/foo/bar/bin"),
+                PathBuf::from("/opt/tools/bin")
+            ]
+        );
+        assert_eq!(
+            cf.env_path_after(),
+            vec![PathBuf::from("/foo/bar/node_modules/.bin")]
+        );
+    }
+
+    #[test]
+    fn test_invalid_env_path() {
+        let err = RTXFile::from_str(
+            r#"
+env_path = "./bin"
+"#
+            .to_string(),
+        )
+        .unwrap_err();
+
+        assert_display_snapshot!(err, @r###"expected env_path to be an array, got: "./bin""###);
     }
 
     #[test]
@@ -526,11 +876,12 @@ nodejs=[true]
         )
         .unwrap();
         let mut cf = RTXFile::from_file(f.path()).unwrap();
-        cf.update_setting("legacy_version_file", false);
-        cf.update_setting("something_else", "foo");
-        cf.update_setting("something.nested.very.deeply", 123);
-        cf.update_setting("aliases.nodejs.20", "20.0.0");
-        cf.update_setting("aliases.python.3", "3.9.0");
+        cf.update_setting("legacy_version_file", false).unwrap();
+        cf.update_setting("something_else", "foo").unwrap();
+        cf.update_setting("something.nested.very.deeply", 123)
+            .unwrap();
+        cf.update_setting("aliases.nodejs.20", "20.0.0").unwrap();
+        cf.update_setting("aliases.python.3", "3.9.0").unwrap();
         assert_display_snapshot!(cf.dump(), @r###"
         legacy_version_file = false
         something_else = "foo"
@@ -567,7 +918,7 @@ nodejs=[true]
         )
         .unwrap();
         let mut cf = RTXFile::from_file(f.path()).unwrap();
-        cf.remove_setting("something.nested.other");
+        cf.remove_setting("something.nested.other").unwrap();
         assert_display_snapshot!(cf.dump(), @r###"
         [something]
 
@@ -584,7 +935,7 @@ nodejs=[true]
         writedoc!(
             f,
             r#"
-            [aliases.nodejs]
+            [alias.nodejs]
             16 = "16.0.0"
             18 = "18.0.0"
         "#
@@ -595,14 +946,42 @@ nodejs=[true]
         cf.set_alias("nodejs", "20", "20.0.0");
         cf.set_alias("python", "3.10", "3.10.0");
         assert_display_snapshot!(cf.dump(), @r###"
-        [aliases.nodejs]
+        [alias.nodejs]
         16 = "16.0.0"
         18 = "18.0.1"
         20 = "20.0.0"
 
-        [aliases.python]
+        [alias.python]
         "3.10" = "3.10.0"
         "###);
+        assert_eq!(cf.aliases()["nodejs"]["18"], "18.0.1");
+    }
+
+    #[test]
+    fn test_remove_alias() {
+        let mut f = tempfile::NamedTempFile::new().unwrap();
+        writedoc!(
+            f,
+            r#"
+            # my aliases
+            [alias.nodejs]
+            16 = "16.0.0" # old
+            18 = "18.0.0"
+
+            [alias.python]
+            "3.10" = "3.10.0"
+        "#
+        )
+        .unwrap();
+        let mut cf = RTXFile::from_file(f.path()).unwrap();
+        cf.remove_alias("nodejs", "16");
+        cf.remove_alias("python", "3.10");
+        assert_display_snapshot!(cf.dump(), @r###"
+        # my aliases
+        [alias.nodejs]
+        18 = "18.0.0"
+        "###);
+        assert!(!cf.aliases()["nodejs"].contains_key("16"));
     }
 
     #[test]
@@ -4,12 +4,14 @@ use std::fs;
 use std::fs::read_to_string;
 use std::path::{Path, PathBuf};
 
-use color_eyre::eyre::Result;
+use color_eyre::eyre::{Result, WrapErr};
 use indexmap::IndexMap;
 use itertools::Itertools;
 
 use crate::config::config_file::{ConfigFile, ConfigFileType};
-use crate::config::PluginSource;
+use crate::config::task::Task;
+use crate::config::{AliasMap, PluginSource};
+use crate::errors::Error::InvalidConfig;
 use crate::file::display_path;
 use crate::plugins::PluginName;
 
@@ -41,9 +43,11 @@ impl ToolVersions {
 
     pub fn from_file(path: &Path) -> Result<Self> {
         trace!("parsing tool-versions: {}", path.display());
+        let tv = Self::parse_str(&read_to_string(path)?)
+            .wrap_err_with(|| InvalidConfig(path.to_path_buf()))?;
         Ok(Self {
             path: path.to_path_buf(),
-            ..Self::parse_str(&read_to_string(path)?)?
+            ..tv
         })
     }
 
@@ -140,6 +144,30 @@ impl ConfigFile for ToolVersions {
         HashMap::new()
     }
 
+    fn aliases(&self) -> AliasMap {
+        AliasMap::new()
+    }
+
+    fn env_remove(&self) -> Vec<String> {
+        vec![]
+    }
+
+    fn env_files(&self) -> Vec<PathBuf> {
+        vec![]
+    }
+
+    fn tasks(&self) -> Vec<Task> {
+        vec![]
+    }
+
+    fn env_path(&self) -> Vec<PathBuf> {
+        vec![]
+    }
+
+    fn env_path_after(&self) -> Vec<PathBuf> {
+        vec![]
+    }
+
     fn remove_plugin(&mut self, plugin: &PluginName) {
         self.plugins.remove(plugin);
     }
@@ -175,8 +203,6 @@ impl ConfigFile for ToolVersions {
 
 #[cfg(test)]
 pub(crate) mod tests {
-    use std::fmt::Debug;
-
     use indoc::indoc;
     use insta::{assert_display_snapshot, assert_snapshot};
     use pretty_assertions::assert_eq;
@@ -215,79 +241,4 @@ pub(crate) mod tests {
         ruby 3.0.5
         "###);
     }
-
-    #[derive(Debug)]
-    pub struct MockToolVersions {
-        pub path: PathBuf,
-        pub plugins: IndexMap<PluginName, Vec<String>>,
-        pub env: HashMap<String, String>,
-    }
-
-    // impl MockToolVersions {
-    //     pub fn new() -> Self {
-    //         Self {
-    //             path: PathBuf::from(".tool-versions"),
-    //             plugins: IndexMap::from([
-    //                 (
-    //                     "python".to_string(),
-    //                     vec!["3.11.0".to_string(), "3.10.0".to_string()],
-    //                 ),
-    //                 ("shellcheck".to_string(), vec!["0.9.0".to_string()]),
-    //                 ("shfmt".to_string(), vec!["3.6.0".to_string()]),
-    //             ]),
-    //             env: HashMap::from([
-    //                 ("FOO".to_string(), "bar".to_string()),
-    //                 ("BAZ".to_string(), "qux".to_string()),
-    //             ]),
-    //         }
-    //     }
-    // }
-    //
-    impl Display for MockToolVersions {
-        fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
-            todo!()
-        }
-    }
-
-    impl ConfigFile for MockToolVersions {
-        fn get_type(&self) -> ConfigFileType {
-            ConfigFileType::ToolVersions
-        }
-
-        fn get_path(&self) -> &Path {
-            self.path.as_path()
-        }
-
-        fn source(&self) -> PluginSource {
-            todo!()
-        }
-
-        fn plugins(&self) -> IndexMap<PluginName, Vec<String>> {
-            self.plugins.clone()
-        }
-
-        fn env(&self) -> HashMap<String, String> {
-            self.env.clone()
-        }
-
-        fn remove_plugin(&mut self, _plugin_name: &PluginName) {
-            todo!()
-        }
-
-        fn add_version(&mut self, _plugin_name: &PluginName, _version: &str) {
-            todo!()
-        }
-
-        fn replace_versions(&mut self, _plugin_name: &PluginName, _versions: &[String]) {
-            todo!()
-        }
-
-        fn save(&self) -> Result<()> {
-            todo!()
-        }
-
-        fn dump(&self) -> String {
-            todo!()
-        }
-    }
 }
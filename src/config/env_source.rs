@@ -0,0 +1,20 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+use crate::file::display_path;
+
+/// where a var in `[env]` or one that is unset came from
+#[derive(Debug, Clone)]
+pub enum EnvSource {
+    ConfigFile(PathBuf),
+    EnvFile(PathBuf),
+}
+
+impl Display for EnvSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EnvSource::ConfigFile(path) => write!(f, "[env] in {}", display_path(path)),
+            EnvSource::EnvFile(path) => write!(f, "env_file {}", display_path(path)),
+        }
+    }
+}
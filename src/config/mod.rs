@@ -6,9 +6,10 @@ use std::sync::Arc;
 
 use color_eyre::eyre::{eyre, Result, WrapErr};
 use color_eyre::Report;
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
 use rayon::prelude::*;
+use tera::Context;
 
 pub use plugin_source::PluginSource;
 pub use settings::{MissingRuntimeBehavior, Settings};
@@ -17,16 +18,27 @@ use crate::cli::args::runtime::{RuntimeArg, RuntimeArgVersion};
 use crate::config::config_file::legacy_version::LegacyVersionFile;
 use crate::config::config_file::rtxrc::RTXFile;
 use crate::config::config_file::ConfigFile;
+use crate::config::env_source::EnvSource;
+use crate::config::task::Task;
 use crate::config::toolset::Toolset;
+use crate::errors::Error::AliasCycle;
+use crate::file::display_path;
 use crate::plugins::{Plugin, PluginName};
-use crate::{dirs, env, file};
+use crate::{dirs, env, file, timings};
 
+mod asdfrc;
 pub mod config_file;
+pub mod env_source;
 pub mod plugin_source;
+pub mod schema;
 mod settings;
+pub mod task;
 mod toolset;
+pub mod trust;
 
-type AliasMap = IndexMap<PluginName, IndexMap<String, String>>;
+pub type AliasMap = IndexMap<PluginName, IndexMap<String, String>>;
+type EnvMap = IndexMap<String, String>;
+type ConfigFiles = Vec<Box<dyn ConfigFile>>;
 
 #[derive(Debug, Default)]
 pub struct Config {
@@ -34,29 +46,72 @@ pub struct Config {
     pub rtxrc: RTXFile,
     pub ts: Toolset,
     pub config_files: Vec<PathBuf>,
+    pub legacy_filenames: IndexMap<String, PluginName>,
+    pub env_files: Vec<PathBuf>,
     pub aliases: AliasMap,
+    pub env: IndexMap<String, String>,
+    pub env_remove: Vec<String>,
+    /// where each var in `env` and `env_remove` was set
+    pub env_sources: IndexMap<String, EnvSource>,
+    pub env_path: Vec<PathBuf>,
+    pub env_path_after: Vec<PathBuf>,
+    pub tasks: IndexMap<String, Task>,
+    /// project config files of which only the runtime versions are used until they are trusted
+    pub untrusted_config_files: Vec<PathBuf>,
 }
 
 impl Config {
     pub fn load() -> Result<Self> {
-        let rtxrc = load_rtxrc()?;
-        let settings = rtxrc.settings();
+        let _span = timings::span("Config::load");
+        let rtxrc = timings::time("load_rtxrc", load_rtxrc)?;
+        let settings = timings::time("load_settings", || load_settings(&rtxrc))?;
         let mut ts = Toolset::default();
-        load_installed_plugins(&mut ts)?;
-        load_installed_runtimes(&mut ts)?;
-        let legacy_filenames = load_legacy_filenames(&settings, &ts)?;
-        let config_files = find_all_config_files(&legacy_filenames);
-        load_config_files(&mut ts, &config_files, &legacy_filenames)?;
-        load_runtime_env(&mut ts, env::vars().collect())?;
-        let aliases = load_aliases(&settings, &ts)?;
-        ts.resolve_all_versions(&aliases)?;
+        timings::time("load_installed_plugins", || load_installed_plugins(&mut ts))?;
+        timings::time("load_installed_runtimes", || {
+            load_installed_runtimes(&mut ts)
+        })?;
+        let legacy_filenames = timings::time("load_legacy_filenames", || {
+            load_legacy_filenames(&settings, &ts)
+        })?;
+        let config_files = timings::time("find_all_config_files", || {
+            find_all_config_files(&legacy_filenames)
+        });
+        let (parsed_config_files, untrusted_config_files) =
+            timings::time("load_config_files", || {
+                load_config_files(&mut ts, &config_files, &legacy_filenames)
+            })?;
+        timings::time("load_runtime_env", || {
+            load_runtime_env(&mut ts, env::vars().collect())
+        })?;
+        let aliases = timings::time("load_aliases", || {
+            load_aliases(&rtxrc, &parsed_config_files, &ts)
+        })?;
+        timings::time("resolve_all_versions", || ts.resolve_all_versions(&aliases))?;
+        let (env, env_remove, env_sources) =
+            timings::time("load_env", || load_env(&rtxrc, &parsed_config_files, &ts))?;
+        let (env_path, env_path_after) = load_env_path(&rtxrc, &parsed_config_files);
+        let tasks = load_tasks(&rtxrc, &parsed_config_files);
+        // missing env_files are kept so they are watched for being created
+        let env_files = std::iter::once(&rtxrc as &dyn ConfigFile)
+            .chain(parsed_config_files.iter().map(|cf| cf.as_ref()))
+            .flat_map(|cf| cf.env_files())
+            .collect();
 
         let config = Self {
             settings,
             ts,
             config_files,
+            legacy_filenames,
+            env_files,
             aliases,
             rtxrc,
+            env,
+            env_remove,
+            env_sources,
+            env_path,
+            env_path_after,
+            tasks,
+            untrusted_config_files,
         };
 
         debug!("{}", &config);
@@ -64,6 +119,15 @@ impl Config {
         Ok(config)
     }
 
+    /// warns about the config files which are not trusted, this is not done while loading so
+    /// `hook-env` only warns when it runs rather than on every prompt
+    pub fn warn_untrusted(&self) {
+        for path in &self.untrusted_config_files {
+            let path = display_path(path);
+            warn!("{path} is not trusted, only the runtime versions in it are used. Trust it with `rtx trust {path}`");
+        }
+    }
+
     pub fn env(&self) -> Result<IndexMap<String, String>> {
         let mut entries = self
             .ts
@@ -75,11 +139,15 @@ impl Config {
             .flatten()
             .collect_vec();
         entries.par_sort();
-        Ok(entries.into_iter().collect())
+        let mut env: IndexMap<String, String> = entries.into_iter().collect();
+        env.extend(self.env.clone());
+        env.retain(|k, _| !self.env_remove.contains(k));
+        Ok(env)
     }
 
+    /// paths to add to PATH: config env_path, runtime bin paths, then config env_path_after
     pub fn list_paths(&self) -> Result<Vec<PathBuf>> {
-        let paths = self
+        let installs = self
             .ts
             .list_current_installed_versions()
             .into_par_iter()
@@ -88,6 +156,11 @@ impl Config {
             .into_iter()
             .flatten()
             .collect::<Vec<PathBuf>>();
+        let paths = [self.env_path.clone(), installs, self.env_path_after.clone()]
+            .concat()
+            .into_iter()
+            .unique()
+            .collect();
         Ok(paths)
     }
 
@@ -128,13 +201,8 @@ impl Config {
         Ok(())
     }
 
-    pub fn resolve_alias(&self, plugin: &str, version: String) -> String {
-        if let Some(plugin_aliases) = self.aliases.get(plugin) {
-            if let Some(alias) = plugin_aliases.get(&version) {
-                return alias.clone();
-            }
-        }
-        version
+    pub fn resolve_alias(&self, plugin: &str, version: String) -> Result<String> {
+        resolve_alias(&self.aliases, plugin, &version)
     }
 
     pub fn resolve_runtime_arg(&mut self, arg: &RuntimeArg) -> Result<Option<String>> {
@@ -143,7 +211,7 @@ impl Config {
             RuntimeArgVersion::Version(version) => {
                 let plugin = self.ts.get_or_add_plugin(arg.plugin.to_string())?;
                 plugin.ensure_installed(&self.settings)?;
-                let version = self.resolve_alias(&arg.plugin, version.into());
+                let version = self.resolve_alias(&arg.plugin, version.into())?;
                 let version = plugin.latest_version(&version)?.unwrap_or(version);
                 Ok(Some(version))
             }
@@ -165,6 +233,33 @@ impl Config {
     }
 }
 
+/// resolves `version` through the plugin's aliases. Aliases can point at other aliases,
+/// e.g.: lts -> lts-hydrogen -> 18
+pub fn resolve_alias(aliases: &AliasMap, plugin: &str, version: &str) -> Result<String> {
+    let chain = alias_chain(aliases, plugin, version)?;
+    Ok(chain.last().unwrap().to_string())
+}
+
+/// `version` followed by each alias it resolves through, e.g.: [lts, lts-hydrogen, 18]
+pub fn alias_chain(aliases: &AliasMap, plugin: &str, version: &str) -> Result<Vec<String>> {
+    let mut seen = vec![version.to_string()];
+    let plugin_aliases = match aliases.get(plugin) {
+        Some(plugin_aliases) => plugin_aliases,
+        None => return Ok(seen),
+    };
+    let mut version = version;
+    while let Some(to) = plugin_aliases.get(version) {
+        let is_cycle = seen.contains(to);
+        seen.push(to.clone());
+        if is_cycle {
+            return Err(AliasCycle(plugin.into(), seen).into());
+        }
+        trace!("resolved alias: {}@{} -> {}", plugin, version, to);
+        version = to;
+    }
+    Ok(seen)
+}
+
 fn load_rtxrc() -> Result<RTXFile> {
     let settings_path = dirs::CONFIG.join("config.toml");
     let rtxrc = if !settings_path.exists() {
@@ -180,6 +275,13 @@ fn load_rtxrc() -> Result<RTXFile> {
     Ok(rtxrc)
 }
 
+/// settings from .asdfrc with the rtx config file taking precedence
+fn load_settings(rtxrc: &RTXFile) -> Result<Settings> {
+    let mut settings = asdfrc::load(&env::ASDF_CONFIG_FILE)?;
+    settings.merge(rtxrc.settings_builder());
+    Ok(settings.build())
+}
+
 fn load_installed_plugins(ts: &mut Toolset) -> Result<()> {
     let plugins = file::dir_subdirs(&dirs::PLUGINS)?
         .into_par_iter()
@@ -230,12 +332,18 @@ fn load_legacy_filenames(
     Ok(filenames)
 }
 
+/// config filenames in each directory from highest to lowest precedence
 fn find_all_config_files(legacy_filenames: &IndexMap<String, PluginName>) -> Vec<PathBuf> {
-    let mut filenames = vec![
-        // ".rtxrc.toml",
-        // ".rtxrc",
+    let env_filename = env::RTX_ENV.as_ref().map(|e| format!(".rtx.{e}.toml"));
+    let mut filenames = vec![".rtx.local.toml"];
+    if let Some(env_filename) = &env_filename {
+        filenames.push(env_filename.as_str());
+    }
+    filenames.extend([
+        ".rtxrc.toml",
+        ".rtxrc",
         env::RTX_DEFAULT_TOOL_VERSIONS_FILENAME.as_str(),
-    ];
+    ]);
     for filename in legacy_filenames.keys() {
         filenames.push(filename.as_str());
     }
@@ -255,40 +363,71 @@ fn load_config_files(
     ts: &mut Toolset,
     config_files: &Vec<PathBuf>,
     legacy_filenames: &IndexMap<String, PluginName>,
-) -> Result<()> {
+) -> Result<(ConfigFiles, Vec<PathBuf>)> {
     let parsed_config_files = config_files
         .into_par_iter()
         .rev()
-        .filter_map(|path| {
-            let filename = path.file_name().unwrap().to_string_lossy().to_string();
-            let result = match legacy_filenames.get(&filename) {
-                Some(plugin) => {
-                    let plugin = ts.find_plugin(plugin).unwrap();
-                    LegacyVersionFile::parse(path.into(), &plugin)
-                        .map(|cf| Box::new(cf) as Box<dyn ConfigFile>)
-                }
-                None => config_file::parse(path),
-            };
-            match result {
-                Ok(cf) => Some(cf),
-                Err(e) => {
-                    warn!("error parsing config file: {}", e);
-                    None
-                }
+        .filter_map(|path| match parse_config_file(path, legacy_filenames, ts) {
+            Ok(cf) => Some(cf),
+            Err(e) => {
+                warn!("error parsing config file: {:#}", e);
+                None
             }
         })
         .collect::<Vec<_>>();
 
-    for cf in parsed_config_files {
-        let path = cf.get_path().to_path_buf();
-        load_config_file(ts, cf)
-            .with_context(|| eyre!("error loading file: {}", path.display()))?;
+    for cf in &parsed_config_files {
+        load_config_file(ts, cf.as_ref())
+            .with_context(|| eyre!("error loading file: {}", cf.get_path().display()))?;
     }
 
-    Ok(())
+    // only the versions are used from untrusted files, so drop them before loading the rest
+    let (parsed_config_files, untrusted): (Vec<_>, Vec<_>) = parsed_config_files
+        .into_iter()
+        .partition(|cf| is_fully_loaded(cf.as_ref()));
+    let untrusted = untrusted
+        .iter()
+        .map(|cf| cf.get_path().to_path_buf())
+        .collect();
+
+    Ok((parsed_config_files, untrusted))
+}
+
+/// parses a project config file, legacy version files are parsed by the plugin they belong to
+pub fn parse_config_file(
+    path: &Path,
+    legacy_filenames: &IndexMap<String, PluginName>,
+    ts: &Toolset,
+) -> Result<Box<dyn ConfigFile>> {
+    let filename = path.file_name().unwrap().to_string_lossy().to_string();
+    match legacy_filenames.get(&filename) {
+        Some(plugin) => {
+            let plugin = ts.find_plugin(plugin).unwrap();
+            let cf = LegacyVersionFile::parse(path.into(), &plugin)?;
+            Ok(Box::new(cf))
+        }
+        None => config_file::parse(path),
+    }
+}
+
+/// whether everything in a config file is used rather than only its runtime versions,
+/// which is the case if it is trusted or doesn't set anything else
+pub fn is_fully_loaded(cf: &dyn ConfigFile) -> bool {
+    is_versions_only(cf) || trust::is_trusted(cf.get_path())
+}
+
+/// config files that only set runtime versions can't change the environment so don't need trust
+fn is_versions_only(cf: &dyn ConfigFile) -> bool {
+    cf.env().is_empty()
+        && cf.env_remove().is_empty()
+        && cf.env_files().is_empty()
+        && cf.env_path().is_empty()
+        && cf.env_path_after().is_empty()
+        && cf.tasks().is_empty()
+        && cf.aliases().is_empty()
 }
 
-fn load_config_file(ts: &mut Toolset, cf: Box<dyn ConfigFile>) -> Result<()> {
+fn load_config_file(ts: &mut Toolset, cf: &dyn ConfigFile) -> Result<()> {
     trace!("config file: {}", cf);
     for (plugin, versions) in cf.plugins() {
         ts.set_current_runtime_versions(&plugin, versions.clone(), cf.source())?;
@@ -300,8 +439,11 @@ fn load_config_file(ts: &mut Toolset, cf: Box<dyn ConfigFile>) -> Result<()> {
 fn load_runtime_env(ts: &mut Toolset, env: IndexMap<String, String>) -> Result<()> {
     for (k, v) in env {
         if k.starts_with("RTX_") && k.ends_with("_VERSION") {
-            let plugin_name = k[4..k.len() - 8].to_lowercase();
-            if let Some(plugin) = ts.find_plugin(&plugin_name) {
+            let plugin = ts
+                .list_plugins()
+                .into_iter()
+                .find(|p| runtime_version_env_key(&p.name) == k);
+            if let Some(plugin) = plugin {
                 if plugin.is_installed() {
                     let source = PluginSource::Environment(k, v.clone());
                     ts.set_current_runtime_versions(&plugin.name, vec![v], source)?;
@@ -312,7 +454,123 @@ fn load_runtime_env(ts: &mut Toolset, env: IndexMap<String, String>) -> Result<(
     Ok(())
 }
 
-fn load_aliases(settings: &Settings, ts: &Toolset) -> Result<AliasMap> {
+/// the env var which overrides the version of `plugin`, e.g.: RTX_NODE_BUILD_VERSION for node-build
+pub fn runtime_version_env_key(plugin: &str) -> String {
+    format!("RTX_{}_VERSION", plugin.to_uppercase().replace('-', "_"))
+}
+
+/// builds the [env] section of every config file, global config first, then from the furthest
+/// config file to the closest. Values are rendered as tera templates after versions are resolved
+/// so they can reference the active runtimes. Dotenv files referenced by a config file are loaded
+/// before its [env] section so they can be overridden or referenced there.
+/// Also returns the vars set to `false` which should be unset and where each var was set.
+fn load_env(
+    rtxrc: &RTXFile,
+    config_files: &[Box<dyn ConfigFile>],
+    ts: &Toolset,
+) -> Result<(EnvMap, Vec<String>, IndexMap<String, EnvSource>)> {
+    let mut runtimes = HashMap::new();
+    for rtv in ts.list_current_versions() {
+        runtimes.entry(rtv.plugin.name.clone()).or_insert_with(|| {
+            HashMap::from([
+                ("path", rtv.install_path.to_string_lossy().to_string()),
+                ("version", rtv.version.clone()),
+            ])
+        });
+    }
+    let mut env = IndexMap::new();
+    let mut env_remove = IndexSet::new();
+    let mut sources = IndexMap::new();
+    let config_files =
+        std::iter::once(rtxrc as &dyn ConfigFile).chain(config_files.iter().map(|cf| cf.as_ref()));
+    for cf in config_files {
+        for path in cf.env_files() {
+            if !path.is_file() {
+                trace!("dotenv file does not exist: {}", path.display());
+                continue;
+            }
+            for item in dotenvy::from_path_iter(&path)? {
+                let (k, v) = item.wrap_err_with(|| eyre!("failed to parse {}", path.display()))?;
+                env_remove.remove(&k);
+                sources.insert(k.clone(), EnvSource::EnvFile(path.clone()));
+                env.insert(k, v);
+            }
+        }
+        let mut ctx = Context::new();
+        let mut ctx_env = env::PRISTINE_ENV.clone();
+        ctx_env.retain(|k, _| !env_remove.contains(k));
+        ctx_env.extend(env.clone());
+        ctx.insert("env", &ctx_env);
+        ctx.insert("runtimes", &runtimes);
+        if let Some(config_root) = cf.get_path().parent() {
+            ctx.insert("config_root", &config_root.to_string_lossy());
+        }
+        for (k, v) in cf.env().into_iter().sorted() {
+            let v = render_env_value(&v, &ctx).wrap_err_with(|| {
+                eyre!("failed to render [env] {k} in {}", cf.get_path().display())
+            })?;
+            env_remove.remove(&k);
+            sources.insert(
+                k.clone(),
+                EnvSource::ConfigFile(cf.get_path().to_path_buf()),
+            );
+            env.insert(k, v);
+        }
+        for k in cf.env_remove() {
+            env.remove(&k);
+            sources.insert(
+                k.clone(),
+                EnvSource::ConfigFile(cf.get_path().to_path_buf()),
+            );
+            env_remove.insert(k);
+        }
+    }
+    Ok((env, env_remove.into_iter().collect(), sources))
+}
+
+/// collects env_path/env_path_after entries, closer config files come first in PATH
+/// and the global config comes last
+fn load_env_path(
+    rtxrc: &RTXFile,
+    config_files: &[Box<dyn ConfigFile>],
+) -> (Vec<PathBuf>, Vec<PathBuf>) {
+    let config_files = config_files
+        .iter()
+        .rev()
+        .map(|cf| cf.as_ref())
+        .chain(std::iter::once(rtxrc as &dyn ConfigFile))
+        .collect_vec();
+    let env_path = config_files.iter().flat_map(|cf| cf.env_path()).collect();
+    let env_path_after = config_files
+        .iter()
+        .flat_map(|cf| cf.env_path_after())
+        .collect();
+    (env_path, env_path_after)
+}
+
+/// tasks from closer config files override tasks with the same name from further ones
+fn load_tasks(rtxrc: &RTXFile, config_files: &[Box<dyn ConfigFile>]) -> IndexMap<String, Task> {
+    std::iter::once(rtxrc as &dyn ConfigFile)
+        .chain(config_files.iter().map(|cf| cf.as_ref()))
+        .flat_map(|cf| cf.tasks())
+        .map(|task| (task.name.clone(), task))
+        .collect()
+}
+
+fn render_env_value(value: &str, ctx: &Context) -> Result<String> {
+    if !value.contains("{{") && !value.contains("{%") {
+        return Ok(value.to_string());
+    }
+    Ok(tera::Tera::one_off(value, ctx, false)?)
+}
+
+/// plugin aliases first, then the global config, then project config files from the
+/// furthest to the closest
+fn load_aliases(
+    rtxrc: &RTXFile,
+    config_files: &[Box<dyn ConfigFile>],
+    ts: &Toolset,
+) -> Result<AliasMap> {
     let mut aliases = IndexMap::new();
     for plugin in ts.list_installed_plugins() {
         for (from, to) in plugin.list_aliases()? {
@@ -323,12 +581,14 @@ fn load_aliases(settings: &Settings, ts: &Toolset) -> Result<AliasMap> {
         }
     }
 
-    for (plugin, plugin_aliases) in &settings.aliases {
-        for (from, to) in plugin_aliases {
+    let config_files =
+        std::iter::once(rtxrc as &dyn ConfigFile).chain(config_files.iter().map(|cf| cf.as_ref()));
+    for cf in config_files {
+        for (plugin, plugin_aliases) in cf.aliases() {
             aliases
-                .entry(plugin.clone())
+                .entry(plugin)
                 .or_insert_with(IndexMap::new)
-                .insert(from.clone(), to.clone());
+                .extend(plugin_aliases);
         }
     }
 
@@ -371,3 +631,63 @@ impl Display for Config {
         write!(f, "  Active Versions: {}", versions.join(", "))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_str_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_runtime_version_env_key() {
+        assert_str_eq!(runtime_version_env_key("tiny"), "RTX_TINY_VERSION");
+        assert_str_eq!(
+            runtime_version_env_key("node-build"),
+            "RTX_NODE_BUILD_VERSION"
+        );
+    }
+
+    #[test]
+    fn test_render_env_value() {
+        let mut ctx = Context::new();
+        ctx.insert("env", &HashMap::from([("HOME", "/home/rtx")]));
+        ctx.insert("config_root", "/src/myproj");
+        ctx.insert(
+            "runtimes",
+            &HashMap::from([(
+                "python",
+                HashMap::from([("path", "/installs/python/3.11.0"), ("version", "3.11.0")]),
+            )]),
+        );
+        let render = |v: &str| render_env_value(v, &ctx).unwrap();
+        assert_str_eq!(render("plain"), "plain");
+        assert_str_eq!(render("{{env.HOME}}/bin"), "/home/rtx/bin");
+        assert_str_eq!(render("{{config_root}}/lib"), "/src/myproj/lib");
+        assert_str_eq!(
+            render("{{runtimes.python.path}}/lib/python{{runtimes.python.version}}"),
+            "/installs/python/3.11.0/lib/python3.11.0"
+        );
+        assert!(render_env_value("{{runtimes.nodejs.path}}", &ctx).is_err());
+    }
+
+    #[test]
+    fn test_resolve_alias() {
+        let aliases = AliasMap::from([(
+            "nodejs".into(),
+            IndexMap::from([
+                ("lts".into(), "lts-hydrogen".into()),
+                ("lts-hydrogen".into(), "18".into()),
+                ("a".into(), "b".into()),
+                ("b".into(), "a".into()),
+            ]),
+        )]);
+        let resolve = |v: &str| resolve_alias(&aliases, "nodejs", v);
+        assert_str_eq!(resolve("lts").unwrap(), "18");
+        assert_str_eq!(resolve("20").unwrap(), "20");
+        assert_str_eq!(resolve_alias(&aliases, "ruby", "lts").unwrap(), "lts");
+        assert_str_eq!(
+            resolve("a").unwrap_err().to_string(),
+            "[nodejs] alias cycle: a -> b -> a"
+        );
+    }
+}
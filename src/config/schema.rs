@@ -0,0 +1,411 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+use serde::de::{Deserialize, Deserializer, Error, MapAccess, SeqAccess, Visitor};
+use toml::Spanned;
+
+use crate::errors::{did_you_mean, suggestions};
+use crate::plugins::Plugin;
+use crate::shorthand::shorthand_to_repository;
+
+/// JSON Schema for config.toml and project config files, editors can use it for completion
+pub static SCHEMA: &str = include_str!("../../schema/rtx.json");
+
+/// the types of the keys which are not plugins, these must match the properties in SCHEMA
+const KEYS: &[(&str, Type)] = &[
+    ("env", Type::Env),
+    ("env_file", Type::StringOrStrings),
+    ("env_path", Type::Strings),
+    ("env_path_after", Type::Strings),
+    ("tasks", Type::Tasks),
+    ("alias", Type::Aliases),
+    ("missing_runtime_behavior", Type::MissingRuntimeBehavior),
+    ("legacy_version_file", Type::Boolean),
+    ("always_keep_download", Type::Boolean),
+    ("plugin_autoupdate_last_check_duration", Type::Integer),
+    ("verbose", Type::Boolean),
+    ("binary_cache", Type::String),
+    ("get_path", Type::Any),
+    ("disable_plugin_short_name_repository", Type::Any),
+    ("plugin_repository_last_check_duration", Type::Any),
+];
+
+const TASK_KEYS: &[&str] = &["run", "description", "depends", "dir", "env"];
+
+#[derive(Clone, Copy)]
+enum Type {
+    Any,
+    Boolean,
+    Integer,
+    String,
+    Strings,
+    StringOrStrings,
+    MissingRuntimeBehavior,
+    Env,
+    Tasks,
+    Aliases,
+}
+
+/// an unknown key or a value of the wrong type in a config file
+#[derive(Debug, PartialEq, Eq)]
+pub struct Problem {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+/// checks an rtx config file against the schema. Unlike parsing the file, this reports
+/// unknown keys: any top-level key that isn't a setting is treated as a plugin so it
+/// must be installed or in the shorthand repository.
+pub fn validate(body: &str) -> Vec<Problem> {
+    let root = match toml::from_str::<Node>(body) {
+        Ok(root) => root,
+        Err(err) => {
+            let offset = err.span().map(|s| s.start).unwrap_or_default();
+            return vec![problem(body, &(offset..offset), err.message().trim())];
+        }
+    };
+    let mut validator = Validator {
+        body,
+        problems: vec![],
+    };
+    if let Node::Table(table) = root {
+        for (key, value) in &table {
+            validator.check_toplevel_key(key, value);
+        }
+    }
+    validator.problems
+}
+
+struct Validator<'a> {
+    body: &'a str,
+    problems: Vec<Problem>,
+}
+
+impl Validator<'_> {
+    fn check_toplevel_key(&mut self, key: &Spanned<String>, value: &Node) {
+        let name = key.get_ref().to_lowercase();
+        match KEYS.iter().find(|(k, _)| *k == name) {
+            Some((_, t)) => self.check_type(key, &name, value, *t),
+            None => self.check_plugin(key, &name, value),
+        }
+    }
+
+    fn check_plugin(&mut self, key: &Spanned<String>, name: &str, value: &Node) {
+        if !is_known_plugin(name) {
+            let similar = suggestions(name, KEYS.iter().map(|(k, _)| *k));
+            let msg = match similar.is_empty() {
+                true => format!("unknown key or plugin: {name}"),
+                false => format!("unknown key: {name}{}", did_you_mean(&similar)),
+            };
+            return self.report(key, msg);
+        }
+        match value {
+            Node::String(_) | Node::Table(_) => {}
+            Node::Array(a) if a.iter().all(|v| matches!(v, Node::String(_))) => {}
+            Node::Array(_) => self.report(key, format!("expected {name} versions to be strings")),
+            _ => self.mismatch(key, name, "a string, array, or table", value),
+        }
+    }
+
+    fn check_type(&mut self, key: &Spanned<String>, name: &str, value: &Node, t: Type) {
+        match (t, value) {
+            (Type::Any, _) => {}
+            (Type::Boolean, Node::Boolean(_)) => {}
+            (Type::Boolean, _) => self.mismatch(key, name, "a boolean", value),
+            (Type::Integer, Node::Integer(i)) if *i >= 0 => {}
+            (Type::Integer, _) => self.mismatch(key, name, "a positive integer", value),
+            (Type::String | Type::StringOrStrings, Node::String(_)) => {}
+            (Type::String, _) => self.mismatch(key, name, "a string", value),
+            (Type::Strings | Type::StringOrStrings, Node::Array(a)) => {
+                if !a.iter().all(|v| matches!(v, Node::String(_))) {
+                    self.report(key, format!("expected {name} values to be strings"));
+                }
+            }
+            (Type::Strings, _) => self.mismatch(key, name, "an array", value),
+            (Type::StringOrStrings, _) => self.mismatch(key, name, "a string or array", value),
+            (Type::MissingRuntimeBehavior, Node::String(s)) => {
+                let options = ["warn", "ignore", "prompt", "autoinstall"];
+                if !options.contains(&s.to_lowercase().as_str()) {
+                    let msg = format!(
+                        "expected {name} to be one of: {}, got: {s}",
+                        options.join(", ")
+                    );
+                    self.report(key, msg);
+                }
+            }
+            (Type::MissingRuntimeBehavior, _) => self.mismatch(key, name, "a string", value),
+            (Type::Env, Node::Table(table)) => {
+                for (k, v) in table {
+                    if !matches!(v, Node::String(_) | Node::Boolean(false)) {
+                        let name = format!("env.{}", k.get_ref());
+                        self.mismatch(k, &name, "a string or false", v);
+                    }
+                }
+            }
+            (Type::Tasks, Node::Table(table)) => {
+                for (k, v) in table {
+                    self.check_task(k, v);
+                }
+            }
+            (Type::Aliases, Node::Table(table)) => {
+                for (plugin, aliases) in table {
+                    let name = format!("alias.{}", plugin.get_ref());
+                    match aliases {
+                        Node::Table(aliases) => {
+                            for (k, v) in aliases {
+                                if !matches!(v, Node::String(_)) {
+                                    let name = format!("{name}.{}", k.get_ref());
+                                    self.mismatch(k, &name, "a string", v);
+                                }
+                            }
+                        }
+                        _ => self.mismatch(plugin, &name, "a table", aliases),
+                    }
+                }
+            }
+            (Type::Env | Type::Tasks | Type::Aliases, _) => {
+                self.mismatch(key, name, "a table", value)
+            }
+        }
+    }
+
+    fn check_task(&mut self, key: &Spanned<String>, value: &Node) {
+        let name = format!("tasks.{}", key.get_ref());
+        let table = match value {
+            Node::String(_) => return,
+            Node::Table(table) => table,
+            _ => return self.mismatch(key, &name, "a string or table", value),
+        };
+        for (k, v) in table {
+            let name = format!("{name}.{}", k.get_ref());
+            match k.get_ref().as_str() {
+                "run" | "description" | "dir" => self.check_string(k, &name, v),
+                "depends" => self.check_type(k, &name, v, Type::Strings),
+                "env" => match v {
+                    Node::Table(env) => {
+                        for (ek, ev) in env {
+                            self.check_string(ek, &format!("{name}.{}", ek.get_ref()), ev);
+                        }
+                    }
+                    _ => self.mismatch(k, &name, "a table", v),
+                },
+                other => {
+                    let similar = suggestions(other, TASK_KEYS.iter().copied());
+                    self.report(k, format!("unknown key: {name}{}", did_you_mean(&similar)));
+                }
+            }
+        }
+    }
+
+    fn check_string(&mut self, key: &Spanned<String>, name: &str, value: &Node) {
+        if !matches!(value, Node::String(_)) {
+            self.mismatch(key, name, "a string", value);
+        }
+    }
+
+    fn mismatch(&mut self, key: &Spanned<String>, name: &str, expected: &str, value: &Node) {
+        let msg = format!(
+            "expected {name} to be {expected}, got: {}",
+            value.type_name()
+        );
+        self.report(key, msg);
+    }
+
+    fn report(&mut self, key: &Spanned<String>, message: String) {
+        self.problems
+            .push(problem(self.body, &key.span(), &message));
+    }
+}
+
+fn problem(body: &str, span: &Range<usize>, message: &str) -> Problem {
+    let before = &body[..span.start.min(body.len())];
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or_default();
+    Problem {
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+        message: message.to_string(),
+    }
+}
+
+fn is_known_plugin(name: &str) -> bool {
+    shorthand_to_repository(name).is_some() || Plugin::new(&name.to_string()).is_installed()
+}
+
+/// a toml value which keeps the position of each key. Values themselves don't always
+/// have a position (e.g.: tables created implicitly by [tasks.test]) so problems are
+/// reported at the key.
+enum Node {
+    String(String),
+    Integer(i64),
+    Float,
+    Boolean(bool),
+    Array(Vec<Node>),
+    Table(Vec<(Spanned<String>, Node)>),
+}
+
+impl Node {
+    fn type_name(&self) -> &'static str {
+        match self {
+            Node::String(_) => "string",
+            Node::Integer(_) => "integer",
+            Node::Float => "float",
+            Node::Boolean(_) => "boolean",
+            Node::Array(_) => "array",
+            Node::Table(_) => "table",
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Node {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NodeVisitor)
+    }
+}
+
+struct NodeVisitor;
+
+impl<'de> Visitor<'de> for NodeVisitor {
+    type Value = Node;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a toml value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Node, E> {
+        Ok(Node::Boolean(v))
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Node, E> {
+        Ok(Node::Integer(v))
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Node, E> {
+        Ok(Node::Integer(v as i64))
+    }
+
+    fn visit_f64<E>(self, _v: f64) -> Result<Node, E> {
+        Ok(Node::Float)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Node, E> {
+        Ok(Node::String(v.to_string()))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Node, A::Error> {
+        let mut array = vec![];
+        while let Some(v) = seq.next_element()? {
+            array.push(v);
+        }
+        Ok(Node::Array(array))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Node, A::Error> {
+        let mut table = vec![];
+        // datetimes are deserialized as a map with a key which has no position
+        while let Some(k) = map
+            .next_key::<Spanned<String>>()
+            .map_err(|_| A::Error::custom("datetimes are not supported"))?
+        {
+            table.push((k, map.next_value()?));
+        }
+        Ok(Node::Table(table))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+    use itertools::Itertools;
+    use pretty_assertions::assert_str_eq;
+
+    use super::*;
+
+    fn validate_to_string(body: &str) -> String {
+        validate(body).iter().join("\n")
+    }
+
+    #[test]
+    fn test_validate() {
+        let body = indoc! {r#"
+            legacy_version_files = true
+            verbose = "yes"
+            tiny = ["1", 2]
+            dummy = true
+            missing_runtime_behavior = "sometimes"
+
+            [env]
+            FOO = "bar"
+            BAR = 1
+
+            [tasks.test]
+            run = "cargo test"
+            depend = ["lint"]
+
+            [alias.tiny]
+            my = 1
+        "#};
+        assert_str_eq!(
+            validate_to_string(body),
+            indoc! {r#"
+            1:1: unknown key: legacy_version_files. Did you mean legacy_version_file?
+            2:1: expected verbose to be a boolean, got: string
+            3:1: expected tiny versions to be strings
+            4:1: expected dummy to be a string, array, or table, got: boolean
+            5:1: expected missing_runtime_behavior to be one of: warn, ignore, prompt, autoinstall, got: sometimes
+            9:1: expected env.BAR to be a string or false, got: integer
+            13:1: unknown key: tasks.test.depend. Did you mean depends?
+            16:1: expected alias.tiny.my to be a string, got: integer"#}
+        );
+    }
+
+    #[test]
+    fn test_validate_valid() {
+        let body = indoc! {r#"
+            tiny = "latest"
+            nodejs = ["18", "16"]
+            env_file = ".env"
+
+            [tasks]
+            lint = "eslint ."
+        "#};
+        assert_str_eq!(validate_to_string(body), "");
+    }
+
+    #[test]
+    fn test_validate_syntax_error() {
+        assert_str_eq!(
+            validate_to_string("tiny = \"1\"\nverbose = \n"),
+            "2:11: invalid string\nexpected `\"`, `'`"
+        );
+        assert_str_eq!(
+            validate_to_string("  x = 1979-05-27"),
+            "1:7: datetimes are not supported"
+        );
+    }
+
+    #[test]
+    fn test_schema_matches_keys() {
+        let schema: serde_json::Value = serde_json::from_str(SCHEMA).unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+        let keys = KEYS
+            .iter()
+            .map(|(k, _)| k.to_string())
+            .sorted()
+            .collect_vec();
+        assert_eq!(properties.keys().cloned().sorted().collect_vec(), keys);
+        let task = &schema["definitions"]["task"]["oneOf"][1]["properties"];
+        let task_keys = task.as_object().unwrap().keys().cloned().sorted();
+        assert_eq!(
+            task_keys.collect_vec(),
+            TASK_KEYS.iter().copied().sorted().collect_vec()
+        );
+    }
+}
@@ -17,6 +17,7 @@ pub struct Settings {
     pub plugin_autoupdate_last_check_duration: Duration,
     pub aliases: IndexMap<PluginName, IndexMap<String, String>>,
     pub verbose: bool,
+    pub binary_cache: Option<String>,
 }
 
 impl Default for Settings {
@@ -28,6 +29,7 @@ impl Default for Settings {
             plugin_autoupdate_last_check_duration: Duration::from_secs(60 * 60 * 24 * 7),
             aliases: IndexMap::new(),
             verbose: *RTX_VERBOSE || !is_tty(),
+            binary_cache: None,
         }
     }
 }
@@ -52,6 +54,9 @@ impl Settings {
             (self.plugin_autoupdate_last_check_duration.as_secs() / 60).to_string(),
         );
         map.insert("verbose".into(), self.verbose.to_string());
+        if let Some(binary_cache) = &self.binary_cache {
+            map.insert("binary_cache".into(), binary_cache.clone());
+        }
         map
     }
 }
@@ -64,6 +69,7 @@ pub struct SettingsBuilder {
     pub plugin_autoupdate_last_check_duration: Option<Duration>,
     pub aliases: Option<AliasMap>,
     pub verbose: Option<bool>,
+    pub binary_cache: Option<String>,
 }
 
 impl SettingsBuilder {
@@ -73,7 +79,7 @@ impl SettingsBuilder {
     //     }
     // }
 
-    pub fn _merge(&mut self, other: Self) -> &mut Self {
+    pub fn merge(&mut self, other: Self) -> &mut Self {
         if other.missing_runtime_behavior.is_some() {
             self.missing_runtime_behavior = other.missing_runtime_behavior;
         }
@@ -93,9 +99,39 @@ impl SettingsBuilder {
         if other.aliases.is_some() {
             self.aliases = other.aliases;
         }
+        if other.binary_cache.is_some() {
+            self.binary_cache = other.binary_cache;
+        }
         self
     }
 
+    /// the settings which are set, without aliases
+    pub fn to_index_map(&self) -> IndexMap<String, String> {
+        let mut map = IndexMap::new();
+        if let Some(v) = &self.missing_runtime_behavior {
+            map.insert("missing_runtime_behavior".into(), v.to_string());
+        }
+        if let Some(v) = self.always_keep_download {
+            map.insert("always_keep_download".into(), v.to_string());
+        }
+        if let Some(v) = self.legacy_version_file {
+            map.insert("legacy_version_file".into(), v.to_string());
+        }
+        if let Some(v) = self.plugin_autoupdate_last_check_duration {
+            map.insert(
+                "plugin_autoupdate_last_check_duration".into(),
+                (v.as_secs() / 60).to_string(),
+            );
+        }
+        if let Some(v) = self.verbose {
+            map.insert("verbose".into(), v.to_string());
+        }
+        if let Some(v) = &self.binary_cache {
+            map.insert("binary_cache".into(), v.clone());
+        }
+        map
+    }
+
     pub fn build(&self) -> Settings {
         let mut settings = Settings::default();
         settings.missing_runtime_behavior = match env::RTX_MISSING_RUNTIME_BEHAVIOR
@@ -123,6 +159,9 @@ impl SettingsBuilder {
             .unwrap_or(settings.plugin_autoupdate_last_check_duration);
         settings.verbose = self.verbose.unwrap_or(settings.verbose);
         settings.aliases = self.aliases.clone().unwrap_or(settings.aliases);
+        settings.binary_cache = env::RTX_BINARY_CACHE
+            .clone()
+            .or_else(|| self.binary_cache.clone());
 
         settings
     }
@@ -159,7 +198,7 @@ mod tests {
             missing_runtime_behavior: Some(AutoInstall),
             ..SettingsBuilder::default()
         };
-        s1._merge(s2);
+        s1.merge(s2);
 
         assert_eq!(s1.missing_runtime_behavior, Some(AutoInstall));
     }
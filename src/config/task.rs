@@ -0,0 +1,47 @@
+use std::path::{Path, PathBuf};
+
+use indexmap::IndexMap;
+
+/// a command defined in the [tasks] section of a config file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Task {
+    pub name: String,
+    pub run: String,
+    pub description: Option<String>,
+    pub depends: Vec<String>,
+    pub dir: Option<PathBuf>,
+    pub env: IndexMap<String, String>,
+    pub config_path: PathBuf,
+}
+
+impl Task {
+    /// directory to run the task in, relative to the config file that defined it
+    pub fn cwd(&self) -> PathBuf {
+        let config_root = self.config_path.parent().unwrap_or(Path::new(""));
+        match &self.dir {
+            Some(dir) => config_root.join(dir).components().collect(),
+            None => config_root.to_path_buf(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_cwd() {
+        let mut task = Task {
+            name: "build".into(),
+            config_path: PathBuf::from("/foo/bar/.rtxrc.toml"),
+            ..Default::default()
+        };
+        assert_eq!(task.cwd(), PathBuf::from("/foo/bar"));
+        task.dir = Some(PathBuf::from("./web"));
+        assert_eq!(task.cwd(), PathBuf::from("/foo/bar/web"));
+        task.dir = Some(PathBuf::from("/tmp"));
+        assert_eq!(task.cwd(), PathBuf::from("/tmp"));
+    }
+}
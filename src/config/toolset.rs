@@ -7,7 +7,7 @@ use itertools::Itertools;
 use rayon::prelude::*;
 use versions::Versioning;
 
-use crate::config::{AliasMap, PluginSource};
+use crate::config::{resolve_alias, AliasMap, PluginSource};
 use crate::plugins::{Plugin, PluginName};
 use crate::runtimes::RuntimeVersion;
 
@@ -99,7 +99,7 @@ impl Toolset {
         self.installed_versions
             .iter()
             .sorted_by_cached_key(|(plugin_name, _)| plugin_name.to_string())
-            .flat_map(|(_, versions)| versions.iter().map(|(_, rtv)| rtv.clone()))
+            .flat_map(|(_, versions)| versions.values().cloned())
             .collect()
     }
 
@@ -130,7 +130,6 @@ impl Toolset {
     }
 
     pub fn resolve_all_versions(&mut self, aliases: &AliasMap) -> Result<()> {
-        let default_aliases = IndexMap::new();
         self.current_versions = self
             .current_versions
             .clone()
@@ -138,29 +137,22 @@ impl Toolset {
             .collect_vec()
             .into_par_iter()
             .map(|(plugin_name, versions)| {
-                let aliases = aliases.get(&plugin_name).unwrap_or(&default_aliases);
                 let plugin = self
                     .find_plugin(&plugin_name)
                     .unwrap_or_else(|| Arc::new(Plugin::new(&plugin_name)));
                 let versions = versions
                     .iter()
                     .map(|v| {
-                        let v = match aliases.get(v) {
-                            Some(version) => {
-                                trace!("resolved alias: {}@{} -> {}", plugin.name, v, version);
-                                version
-                            }
-                            _ => v,
-                        };
-                        match self.resolve_version(&plugin_name, v) {
+                        let v = resolve_alias(aliases, &plugin_name, v)?;
+                        match self.resolve_version(&plugin_name, &v) {
                             Some(rtv) => Ok(rtv.version.clone()),
                             None => {
                                 let latest = if plugin.is_installed() {
-                                    plugin.latest_version(v)?
+                                    plugin.latest_version(&v)?
                                 } else {
                                     Some(v.clone())
                                 };
-                                Ok(latest.unwrap_or_else(|| v.clone()))
+                                Ok(latest.unwrap_or(v))
                             }
                         }
                     })
@@ -214,9 +206,7 @@ impl Toolset {
         plugin: &str,
         prefix: &str,
     ) -> Option<Arc<RuntimeVersion>> {
-        let default_aliases = IndexMap::new();
-        let aliases = aliases.get(plugin).unwrap_or(&default_aliases);
-        let prefix = aliases.get(prefix).cloned().unwrap_or(prefix.to_string());
+        let prefix = resolve_alias(aliases, plugin, prefix).unwrap_or_else(|_| prefix.to_string());
 
         let mut versions = self.list_current_versions();
         versions.extend(self.list_installed_versions());
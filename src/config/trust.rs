@@ -0,0 +1,125 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::Result;
+use sha2::{Digest, Sha256};
+
+use crate::dirs;
+use crate::hash::hash_to_str;
+
+/// Project config files can set env vars, PATH entries and tasks so they are only fully loaded
+/// once trusted with `rtx trust`. Each trusted file has an entry in ~/.local/share/rtx/trusted-configs
+/// named after a hash of its path. The first line of the entry is the path, the optional second
+/// line is a sha256 of the contents which must match for the file to remain trusted.
+pub fn is_trusted(path: &Path) -> bool {
+    let path = canonicalize(path);
+    let entry = match fs::read_to_string(entry_path(&path)) {
+        Ok(entry) => entry,
+        Err(_) => return false,
+    };
+    let mut lines = entry.lines();
+    if lines.next() != Some(path.to_string_lossy().as_ref()) {
+        return false;
+    }
+    match lines.next() {
+        Some(hash) => content_hash(&path).map_or(false, |h| h == hash),
+        None => true,
+    }
+}
+
+/// trusts the file at `path`, if `hash` is set it will only remain trusted while unmodified
+pub fn trust(path: &Path, hash: bool) -> Result<()> {
+    let path = canonicalize(path);
+    let mut entry = format!("{}\n", path.display());
+    if hash {
+        entry.push_str(&format!("{}\n", content_hash(&path)?));
+    }
+    fs::create_dir_all(&*dirs::TRUSTED_CONFIGS)?;
+    fs::write(entry_path(&path), entry)?;
+    Ok(())
+}
+
+/// removes trust for the file at `path`, returns false if it was not trusted
+pub fn untrust(path: &Path) -> Result<bool> {
+    let entry = entry_path(&canonicalize(path));
+    if !entry.exists() {
+        return Ok(false);
+    }
+    fs::remove_file(entry)?;
+    Ok(true)
+}
+
+/// the config files in `dir` which can require trust, e.g.: .rtxrc.toml or .rtx.local.toml
+pub fn config_files_in(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let filename = path.file_name().unwrap().to_string_lossy();
+        let is_rtx_config = filename == ".rtxrc"
+            || filename == ".rtxrc.toml"
+            || (filename.starts_with(".rtx.") && filename.ends_with(".toml"));
+        if is_rtx_config && path.is_file() {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn canonicalize(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn entry_path(path: &Path) -> PathBuf {
+    dirs::TRUSTED_CONFIGS.join(hash_to_str(&path))
+}
+
+fn content_hash(path: &Path) -> Result<String> {
+    let contents = fs::read(path)?;
+    let hash = Sha256::digest(contents);
+    Ok(hash.iter().map(|b| format!("{b:02x}")).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trust() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(".rtxrc.toml");
+        fs::write(&path, "[env]\nFOO = \"bar\"\n").unwrap();
+        assert!(!is_trusted(&path));
+
+        trust(&path, false).unwrap();
+        assert!(is_trusted(&path));
+        fs::write(&path, "[env]\nFOO = \"baz\"\n").unwrap();
+        assert!(is_trusted(&path));
+
+        trust(&path, true).unwrap();
+        assert!(is_trusted(&path));
+        fs::write(&path, "[env]\nFOO = \"bar\"\n").unwrap();
+        assert!(!is_trusted(&path));
+
+        assert!(untrust(&path).unwrap());
+        assert!(!untrust(&path).unwrap());
+        assert!(!is_trusted(&path));
+    }
+
+    #[test]
+    fn test_config_files_in() {
+        let dir = tempfile::tempdir().unwrap();
+        for f in [".rtxrc.toml", ".rtx.local.toml", ".tool-versions", ".rtxrc"] {
+            fs::write(dir.path().join(f), "").unwrap();
+        }
+        let files = config_files_in(dir.path()).unwrap();
+        assert_eq!(
+            files,
+            vec![
+                dir.path().join(".rtx.local.toml"),
+                dir.path().join(".rtxrc"),
+                dir.path().join(".rtxrc.toml"),
+            ]
+        );
+    }
+}
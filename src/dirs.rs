@@ -14,4 +14,5 @@ lazy_static! {
     pub static ref DOWNLOADS: PathBuf = env::RTX_DATA_DIR.join("downloads");
     pub static ref INSTALLS: PathBuf = env::RTX_DATA_DIR.join("installs");
     pub static ref LEGACY_CACHE: PathBuf = env::RTX_DATA_DIR.join("legacy_cache");
+    pub static ref TRUSTED_CONFIGS: PathBuf = env::RTX_DATA_DIR.join("trusted-configs");
 }
@@ -85,6 +85,11 @@ lazy_static! {
     pub static ref RTX_QUIET: bool = var_is_true("RTX_QUIET");
     pub static ref RTX_DEBUG: bool = var_is_true("RTX_DEBUG");
     pub static ref RTX_TRACE: bool = var_is_true("RTX_TRACE");
+    pub static ref RTX_TIMINGS: Option<String> = if cfg!(test) {
+        None
+    } else {
+        get_timings(&ARGS)
+    };
     /// essentially, this is whether we show spinners or build output on runtime install
     pub static ref PRISTINE_ENV: HashMap<String, String> =
         get_pristine_env(&__RTX_DIFF, vars().collect());
@@ -97,9 +102,27 @@ lazy_static! {
     } else {
         var("RTX_DEFAULT_TOOL_VERSIONS_FILENAME").unwrap_or_else(|_| ".tool-versions".into())
     };
+    pub static ref ASDF_CONFIG_FILE: PathBuf = if cfg!(test) {
+        HOME.join(".asdfrc")
+    } else {
+        var_os("ASDF_CONFIG_FILE")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| HOME.join(".asdfrc"))
+    };
+    pub static ref RTX_ENV: Option<String> = if cfg!(test) {
+        None
+    } else {
+        var("RTX_ENV").ok().filter(|v| !v.is_empty())
+    };
     pub static ref DIRENV_DIR: Option<String> = var("DIRENV_DIR").ok();
     pub static ref DIRENV_DIFF: Option<String> = var("DIRENV_DIFF").ok();
     pub static ref RTX_HIDE_OUTDATED_BUILD: bool = var_is_true("RTX_HIDE_OUTDATED_BUILD");
+    pub static ref RTX_STRICT: bool = var_is_true("RTX_STRICT");
+    pub static ref RTX_BINARY_CACHE: Option<String> = if cfg!(test) {
+        None
+    } else {
+        var("RTX_BINARY_CACHE").ok().filter(|v| !v.is_empty())
+    };
 }
 
 fn get_env_diff() -> EnvDiff {
@@ -113,22 +136,33 @@ fn get_env_diff() -> EnvDiff {
     }
 }
 
+/// `--timings` is read here rather than by clap since config is loaded before args are parsed
+/// e.g.: `--timings`, `--timings=trace.json`, RTX_TIMINGS=1 or RTX_TIMINGS=trace.json
+fn get_timings(args: &[String]) -> Option<String> {
+    for arg in args {
+        if arg == "--timings" {
+            return Some("1".into());
+        }
+        if let Some(dest) = arg.strip_prefix("--timings=") {
+            return Some(dest.into());
+        }
+    }
+    // any value other than a false one is the destination
+    var("RTX_TIMINGS").ok().filter(|v| is_true(v))
+}
+
 fn var_is_true(key: &str) -> bool {
     match var(key) {
-        Ok(v) => {
-            let v = v.to_lowercase();
-            !v.is_empty()
-                && v != "n"
-                && v != "no"
-                && v != "false"
-                && v != "0"
-                && v != "off"
-                && v != " "
-        }
+        Ok(v) => is_true(&v),
         Err(_) => false,
     }
 }
 
+fn is_true(v: &str) -> bool {
+    let v = v.to_lowercase();
+    !v.is_empty() && v != "n" && v != "no" && v != "false" && v != "0" && v != "off" && v != " "
+}
+
 /// this returns the environment as if __RTX_DIFF was reversed.
 /// putting the shell back into a state before hook-env was run
 fn get_pristine_env(
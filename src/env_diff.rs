@@ -57,6 +57,19 @@ impl EnvDiff {
         diff
     }
 
+    /// marks vars from `original` as removed so to_patches() unsets them and reverse() restores them
+    pub fn remove<T>(&mut self, original: &HashMap<String, String>, keys: T)
+    where
+        T: IntoIterator<Item = String>,
+    {
+        for key in keys {
+            if let Some(original_val) = original.get(&key) {
+                self.new.remove(&key);
+                self.old.insert(key, original_val.into());
+            }
+        }
+    }
+
     pub fn from_bash_script<T, U, V>(script: &Path, env: T) -> Result<Self>
     where
         T: IntoIterator<Item = (U, V)>,
@@ -135,10 +148,9 @@ impl EnvDiff {
             };
         }
         for (k, v) in self.new.iter() {
-            match self.old.contains_key(k) {
-                false => patches.push(EnvDiffOperation::Add(k.into(), v.into())),
-                true => {}
-            };
+            if !self.old.contains_key(k) {
+                patches.push(EnvDiffOperation::Add(k.into(), v.into()));
+            }
         }
 
         patches
@@ -225,6 +237,36 @@ mod tests {
         "###);
     }
 
+    #[test]
+    fn test_remove() {
+        let mut diff = EnvDiff::new(&new_from_hashmap(), new_to_hashmap());
+        diff.remove(
+            &new_from_hashmap(),
+            vec!["a".into(), "b".into(), "d".into()],
+        );
+        assert_debug_snapshot!(diff, @r###"
+        EnvDiff {
+            old: [
+                "a=1",
+                "b=2",
+            ],
+            new: [
+                "c=4",
+            ],
+        }
+        "###);
+        let patches = diff.reverse().to_patches();
+        let restored = patches
+            .iter()
+            .filter_map(|p| match p {
+                EnvDiffOperation::Add(k, v) => Some((k.as_str(), v.as_str())),
+                _ => None,
+            })
+            .sorted()
+            .collect::<Vec<_>>();
+        assert_eq!(restored, vec![("a", "1"), ("b", "2")]);
+    }
+
     fn new_from_hashmap() -> HashMap<String, String> {
         HashMap::from([("a", "1"), ("b", "2")].map(|(k, v)| (k.into(), v.into())))
     }
@@ -1,22 +1,135 @@
+use std::path::PathBuf;
 use std::process::ExitStatus;
 
+use color_eyre::eyre::Report;
+use itertools::Itertools;
 use thiserror::Error;
 
+use crate::file::display_path;
 use crate::plugins::PluginName;
 
+/// Errors that scripts may want to handle. Each has a stable code which is shown with the
+/// error and a distinct exit code, other errors exit with 1.
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("[{0}] plugin not installed")]
     PluginNotInstalled(PluginName),
+    #[error("unknown plugin: {0}{}", did_you_mean(.1))]
+    UnknownPlugin(PluginName, Vec<String>),
     #[error("[{0}] version {1} not installed")]
     VersionNotInstalled(PluginName, String),
+    #[error("[{0}] no versions found matching {1}{}", did_you_mean(.2))]
+    UnknownVersion(PluginName, String, Vec<String>),
+    #[error("[{0}] alias cycle: {}", .1.join(" -> "))]
+    AliasCycle(PluginName, Vec<String>),
+    #[error("failed to parse {}", display_path(.0))]
+    InvalidConfig(PathBuf),
     #[error("[{}] script exited with non-zero status: {}", .0, render_exit_status(.1))]
     ScriptFailed(PluginName, Option<ExitStatus>),
 }
 
+impl Error {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::PluginNotInstalled(..) => "E001",
+            Error::UnknownPlugin(..) => "E002",
+            Error::VersionNotInstalled(..) => "E003",
+            Error::UnknownVersion(..) => "E004",
+            Error::AliasCycle(..) => "E005",
+            Error::InvalidConfig(..) => "E006",
+            Error::ScriptFailed(..) => "E007",
+        }
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::PluginNotInstalled(..) => 10,
+            Error::UnknownPlugin(..) => 11,
+            Error::VersionNotInstalled(..) => 12,
+            Error::UnknownVersion(..) => 13,
+            Error::AliasCycle(..) => 14,
+            Error::InvalidConfig(..) => 15,
+            Error::ScriptFailed(..) => 16,
+        }
+    }
+
+    /// finds the first of these errors in `report`, it may have been wrapped with context
+    pub fn find(report: &Report) -> Option<&Error> {
+        report
+            .downcast_ref::<Error>()
+            .or_else(|| report.chain().find_map(|e| e.downcast_ref::<Error>()))
+    }
+}
+
+/// the closest matches to `input` in `candidates`, best first
+pub fn suggestions<'a, I>(input: &str, candidates: I) -> Vec<String>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    candidates
+        .into_iter()
+        .unique()
+        .map(|c| (strsim::jaro_winkler(input, c), c))
+        .filter(|(score, _)| *score > 0.8)
+        .sorted_by(|(a, _), (b, _)| b.total_cmp(a))
+        .take(3)
+        .map(|(_, c)| c.to_string())
+        .collect()
+}
+
+pub fn did_you_mean(suggestions: &[String]) -> String {
+    match suggestions.is_empty() {
+        true => String::new(),
+        false => format!(". Did you mean {}?", suggestions.join(", ")),
+    }
+}
+
 fn render_exit_status(exit_status: &Option<ExitStatus>) -> String {
     match exit_status.and_then(|s| s.code()) {
         Some(exit_status) => format!("exit code {exit_status}"),
         None => "no exit status".into(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use color_eyre::eyre::WrapErr;
+    use pretty_assertions::assert_str_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_suggestions() {
+        let plugins = ["nodejs", "node", "ruby", "deno"];
+        assert_eq!(suggestions("nodjs", plugins), vec!["nodejs", "node"]);
+        assert!(suggestions("python", plugins).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_plugin() {
+        let err = Error::UnknownPlugin("nodjs".into(), vec!["nodejs".into()]);
+        assert_str_eq!(
+            err.to_string(),
+            "unknown plugin: nodjs. Did you mean nodejs?"
+        );
+        let err = Error::UnknownPlugin("xyz".into(), vec![]);
+        assert_str_eq!(err.to_string(), "unknown plugin: xyz");
+    }
+
+    #[test]
+    fn test_find() {
+        let report: Result<(), Report> = Err(Error::AliasCycle(
+            "nodejs".into(),
+            vec!["lts".into(), "stable".into(), "lts".into()],
+        )
+        .into());
+        let report = report.wrap_err("error loading config").unwrap_err();
+        let err = Error::find(&report).unwrap();
+        assert_str_eq!(
+            err.to_string(),
+            "[nodejs] alias cycle: lts -> stable -> lts"
+        );
+        assert_eq!(err.code(), "E005");
+        assert_eq!(err.exit_code(), 14);
+    }
+}
@@ -1,4 +1,5 @@
 use std::io;
+use std::os::unix::fs::PermissionsExt;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
@@ -20,6 +21,13 @@ pub fn display_path(path: &Path) -> String {
     }
 }
 
+pub fn is_executable(path: &Path) -> bool {
+    match path.metadata() {
+        Ok(metadata) => metadata.is_file() && metadata.permissions().mode() & 0o111 != 0,
+        Err(_) => false,
+    }
+}
+
 pub fn changed_within(f: &Path, within: Duration) -> Result<bool> {
     let now = std::time::SystemTime::now();
     let last_modified = f.metadata()?.modified()?;
@@ -56,7 +64,8 @@ pub fn dir_subdirs(dir: &Path) -> Result<Vec<String>> {
 
     for entry in dir.read_dir()? {
         let entry = entry?;
-        if entry.file_type()?.is_dir() {
+        // follows symlinks so linked plugins are included
+        if entry.path().is_dir() {
             output.push(entry.file_name().into_string().unwrap());
         }
     }
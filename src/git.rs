@@ -36,18 +36,20 @@ impl Git {
     pub fn update(&self, gitref: Option<String>) -> Result<(String, String)> {
         let gitref = gitref.map_or_else(|| self.remote_default_branch(), Ok)?;
         debug!("updating {} to {}", self.dir.display(), gitref);
+        // fetching with --update-head-ok can move HEAD so this needs to be read first
+        let prev_rev = self.current_sha()?;
         cmd!(
             "git",
             "-C",
             &self.dir,
             "fetch",
+            "--quiet",
             "--prune",
             "--update-head-ok",
             "origin",
             [gitref.as_str(), gitref.as_str()].join(":"),
         )
         .run()?;
-        let prev_rev = self.current_sha()?;
         cmd!(
             "git",
             "-C",
@@ -57,6 +59,7 @@ impl Git {
             "-c",
             "advice.objectNameWarning=false",
             "checkout",
+            "--quiet",
             "--force",
             gitref
         )
@@ -67,6 +70,55 @@ impl Git {
         Ok((prev_rev, post_rev))
     }
 
+    /// fetches `gitref` without checking it out and returns the commits an update would apply
+    pub fn fetch_pending(&self, gitref: Option<String>) -> Result<Vec<String>> {
+        let gitref = gitref.map_or_else(|| self.remote_default_branch(), Ok)?;
+        debug!("fetching {} for {}", gitref, self.dir.display());
+        cmd!("git", "-C", &self.dir, "fetch", "--prune", "origin", gitref)
+            .stderr_null()
+            .run()?;
+        self.log("HEAD", "FETCH_HEAD")
+    }
+
+    /// one line summaries of the commits after `from` up to `to`
+    pub fn log(&self, from: &str, to: &str) -> Result<Vec<String>> {
+        let log = cmd!(
+            "git",
+            "-C",
+            &self.dir,
+            "log",
+            "--oneline",
+            "--no-decorate",
+            format!("{from}..{to}")
+        )
+        .read()?;
+        Ok(log.lines().map(|l| l.to_string()).collect())
+    }
+
+    pub fn current_branch(&self) -> Result<String> {
+        let branch = cmd!("git", "-C", &self.dir, "rev-parse", "--abbrev-ref", "HEAD").read()?;
+        Ok(branch)
+    }
+
+    pub fn current_abbrev_sha(&self) -> Result<String> {
+        let sha = cmd!("git", "-C", &self.dir, "rev-parse", "--short", "HEAD").read()?;
+        Ok(sha)
+    }
+
+    /// true if tracked files have uncommitted changes
+    pub fn is_dirty(&self) -> Result<bool> {
+        let status = cmd!(
+            "git",
+            "-C",
+            &self.dir,
+            "status",
+            "--porcelain",
+            "--untracked-files=no"
+        )
+        .read()?;
+        Ok(!status.is_empty())
+    }
+
     pub fn clone(&self, url: &str) -> Result<()> {
         debug!("cloning {} to {}", url, self.dir.display());
         if let Some(parent) = self.dir.parent() {
@@ -1,4 +1,4 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::prelude::*;
 use std::path::PathBuf;
 use std::time::SystemTime;
@@ -8,6 +8,7 @@ use color_eyre::eyre::Result;
 use flate2::write::GzDecoder;
 use flate2::write::GzEncoder;
 use flate2::Compression;
+use serde_derive::{Deserialize, Serialize};
 
 use crate::config::Config;
 use crate::{dirs, env};
@@ -33,6 +34,7 @@ pub fn should_exit_early(config: &Config) -> bool {
     true
 }
 
+/// true if a watched file changed or an RTX_*_VERSION var was set or changed, e.g.: by `rtx shell`
 fn have_config_files_been_modified(
     env: &HashMap<String, String>,
     watch_files: HashSet<PathBuf>,
@@ -47,8 +49,13 @@ fn have_config_files_been_modified(
                 }
             };
 
+            if watches.version_env != version_env_vars(env) {
+                trace!("RTX_*_VERSION env vars changed");
+                return true;
+            }
+
             // make sure they have exactly the same config filenames
-            let watch_keys = watches.keys().cloned().collect::<HashSet<_>>();
+            let watch_keys = watches.files.keys().cloned().collect::<HashSet<_>>();
             if watch_keys != watch_files {
                 trace!(
                     "config files do not match {:?}",
@@ -58,7 +65,7 @@ fn have_config_files_been_modified(
             }
 
             // check the files to see if they've been altered
-            for (fp, prev_modtime) in watches {
+            for (fp, prev_modtime) in watches.files {
                 if let Ok(modtime) = fp
                     .metadata()
                     .expect("accessing config file modtime")
@@ -77,7 +84,13 @@ fn have_config_files_been_modified(
     }
 }
 
-pub type HookEnvWatches = HashMap<PathBuf, SystemTime>;
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct HookEnvWatches {
+    /// the modtimes of the config files and dirs which affect the env
+    pub files: HashMap<PathBuf, SystemTime>,
+    /// the RTX_*_VERSION vars which override the versions in the config files
+    pub version_env: BTreeMap<String, String>,
+}
 
 pub fn serialize_watches(watches: &HookEnvWatches) -> Result<String> {
     let mut gz = GzEncoder::new(Vec::new(), Compression::fast());
@@ -94,8 +107,52 @@ pub fn deserialize_watches(raw: String) -> Result<HookEnvWatches> {
     Ok(rmp_serde::from_slice(&writer[..])?)
 }
 
+pub fn build_watches(config: &Config) -> Result<HookEnvWatches> {
+    let mut watches = HookEnvWatches {
+        version_env: version_env_vars(&env::vars().collect()),
+        ..HookEnvWatches::default()
+    };
+    for cf in get_watch_files(config) {
+        watches.files.insert(cf.clone(), cf.metadata()?.modified()?);
+    }
+
+    Ok(watches)
+}
+
+pub fn get_watch_files(config: &Config) -> HashSet<PathBuf> {
+    let mut watches = HashSet::new();
+    if dirs::ROOT.exists() {
+        watches.insert(dirs::ROOT.clone());
+    }
+    if dirs::TRUSTED_CONFIGS.exists() {
+        watches.insert(dirs::TRUSTED_CONFIGS.clone());
+    }
+    for cf in &config.config_files {
+        watches.insert(cf.clone());
+    }
+    for env_file in &config.env_files {
+        if env_file.exists() {
+            watches.insert(env_file.clone());
+        } else if let Some(dir) = env_file.parent().filter(|d| d.exists()) {
+            // the directory's mtime changes when the env_file is created
+            watches.insert(dir.to_path_buf());
+        }
+    }
+
+    watches
+}
+
+fn version_env_vars(env: &HashMap<String, String>) -> BTreeMap<String, String> {
+    env.iter()
+        .filter(|(k, _)| k.starts_with("RTX_") && k.ends_with("_VERSION"))
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
+    use std::fs;
+    use std::path::Path;
     use std::time::UNIX_EPOCH;
 
     use crate::dirs;
@@ -111,7 +168,7 @@ mod tests {
         let fp = dirs::CURRENT.join(".tool-versions");
         env.insert(
             "__RTX_WATCH".into(),
-            serialize_watches(&HookEnvWatches::from([(fp.clone(), UNIX_EPOCH)])).unwrap(),
+            serialize_watches(&file_watches(&fp, UNIX_EPOCH)).unwrap(),
         );
         let files = HashSet::from([fp.clone()]);
         assert!(have_config_files_been_modified(&env, files));
@@ -119,49 +176,79 @@ mod tests {
         let modtime = fp.metadata().unwrap().modified().unwrap();
         env.insert(
             "__RTX_WATCH".into(),
-            serialize_watches(&HookEnvWatches::from([(fp.clone(), modtime)])).unwrap(),
+            serialize_watches(&file_watches(&fp, modtime)).unwrap(),
         );
         let files = HashSet::from([fp]);
         assert!(!have_config_files_been_modified(&env, files));
     }
 
+    #[test]
+    fn test_rtx_shell_forces_hook_env() {
+        // `rtx shell tiny@2` under `rtx activate` only sets RTX_TINY_VERSION, no files change
+        let fp = dirs::CURRENT.join(".tool-versions");
+        let modtime = fp.metadata().unwrap().modified().unwrap();
+        let mut env = HashMap::from([("RTX_TINY_VERSION".to_string(), "1.0.1".to_string())]);
+        let watches = HookEnvWatches {
+            version_env: version_env_vars(&env),
+            ..file_watches(&fp, modtime)
+        };
+        env.insert("__RTX_WATCH".into(), serialize_watches(&watches).unwrap());
+        let files = HashSet::from([fp.clone()]);
+        assert!(!have_config_files_been_modified(&env, files.clone()));
+
+        env.insert("RTX_TINY_VERSION".into(), "2.1.0".into());
+        assert!(have_config_files_been_modified(&env, files.clone()));
+
+        env.remove("RTX_TINY_VERSION");
+        assert!(have_config_files_been_modified(&env, files));
+    }
+
+    #[test]
+    fn test_get_watch_files_missing_env_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let env_file = dir.path().join(".env");
+        let mut config = Config::load().unwrap();
+        config.env_files = vec![env_file.clone()];
+        let watches = get_watch_files(&config);
+        assert!(watches.contains(dir.path()));
+        assert!(!watches.contains(&env_file));
+
+        fs::write(&env_file, "FOO=bar\n").unwrap();
+        let watches = get_watch_files(&config);
+        assert!(watches.contains(&env_file));
+    }
+
     #[test]
     fn test_serialize_watches_empty() {
-        let serialized = serialize_watches(&HookEnvWatches::new()).unwrap();
+        let serialized = serialize_watches(&HookEnvWatches::default()).unwrap();
         let deserialized = deserialize_watches(serialized).unwrap();
-        assert_eq!(deserialized.len(), 0);
+        assert_eq!(deserialized.files.len(), 0);
+        assert_eq!(deserialized.version_env.len(), 0);
     }
 
     #[test]
     fn test_serialize_watches() {
-        let serialized =
-            serialize_watches(&HookEnvWatches::from([("foo".into(), UNIX_EPOCH)])).unwrap();
+        let mut watches = file_watches(&PathBuf::from("foo"), UNIX_EPOCH);
+        watches
+            .version_env
+            .insert("RTX_TINY_VERSION".into(), "1".into());
+        let serialized = serialize_watches(&watches).unwrap();
         let deserialized = deserialize_watches(serialized).unwrap();
-        assert_eq!(deserialized.len(), 1);
+        assert_eq!(deserialized.files.len(), 1);
         assert_eq!(
-            deserialized.get(PathBuf::from("foo").as_path()).unwrap(),
+            deserialized
+                .files
+                .get(PathBuf::from("foo").as_path())
+                .unwrap(),
             &UNIX_EPOCH
         );
+        assert_eq!(deserialized.version_env["RTX_TINY_VERSION"], "1");
     }
-}
 
-pub fn build_watches(config: &Config) -> Result<HookEnvWatches> {
-    let mut watches = HookEnvWatches::new();
-    for cf in get_watch_files(config) {
-        watches.insert(cf.clone(), cf.metadata()?.modified()?);
-    }
-
-    Ok(watches)
-}
-
-pub fn get_watch_files(config: &Config) -> HashSet<PathBuf> {
-    let mut watches = HashSet::new();
-    if dirs::ROOT.exists() {
-        watches.insert(dirs::ROOT.clone());
-    }
-    for cf in &config.config_files {
-        watches.insert(cf.clone());
+    fn file_watches(fp: &Path, modtime: SystemTime) -> HookEnvWatches {
+        HookEnvWatches {
+            files: HashMap::from([(fp.to_path_buf(), modtime)]),
+            ..HookEnvWatches::default()
+        }
     }
-
-    watches
 }
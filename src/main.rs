@@ -2,6 +2,8 @@ extern crate core;
 #[macro_use]
 extern crate log;
 
+use std::process::ExitCode;
+
 use color_eyre::eyre::Result;
 
 use crate::cli::version::VERSION;
@@ -15,6 +17,7 @@ mod output;
 #[macro_use]
 mod regex;
 
+mod binary_cache;
 pub mod build_time;
 mod cli;
 mod cmd;
@@ -39,25 +42,40 @@ mod shorthand;
 mod shorthand_list;
 #[cfg(test)]
 mod test;
+mod timings;
 
-fn main() -> Result<()> {
+fn main() -> Result<ExitCode> {
     color_eyre::install()?;
     let log_level = *env::RTX_LOG_LEVEL;
     logger::init(log_level, *env::RTX_LOG_FILE_LEVEL);
 
-    match run(&env::ARGS) {
+    let result = run(&env::ARGS);
+    // the timings are reported even when the command fails, but never replace its error
+    if let Err(err) = timings::report() {
+        warn!("failed to report timings: {err:#}");
+    }
+    match result {
         Err(err) if log_level < log::LevelFilter::Debug => {
-            error!("{err}");
+            let typed = errors::Error::find(&err);
+            match typed {
+                Some(e) => error!("{err} ({})", e.code()),
+                None => error!("{err}"),
+            }
             // TODO: tell user they can use --log-level when it's implemented
             error!("Run with RTX_DEBUG=1 for more information.");
             error!("rtx {}", *VERSION);
-            std::process::exit(1);
+            std::process::exit(typed.map_or(1, |e| e.exit_code()));
+        }
+        Err(err) => {
+            let exit_code = errors::Error::find(&err).map_or(1, |e| e.exit_code());
+            eprintln!("Error: {err:?}");
+            std::process::exit(exit_code);
         }
         result => result,
     }
 }
 
-fn run(args: &Vec<String>) -> Result<()> {
+fn run(args: &Vec<String>) -> Result<ExitCode> {
     let out = &mut Output::new();
 
     // show version before loading config in case of error
@@ -65,8 +83,10 @@ fn run(args: &Vec<String>) -> Result<()> {
 
     let config = Config::load()?;
     if hook_env::should_exit_early(&config) {
-        return Ok(());
+        return Ok(ExitCode::SUCCESS);
     }
+    config.warn_untrusted();
     let cli = Cli::new_with_external_commands(&config)?;
-    cli.run(config, args, out)
+    cli.run(config, args, out)?;
+    Ok(out.status)
 }
@@ -17,13 +17,15 @@ use cache::PluginCache;
 pub use script_manager::{InstallType, Script, ScriptManager};
 
 use crate::cmd::cmd;
-use crate::config::{MissingRuntimeBehavior, Settings};
-use crate::errors::Error::PluginNotInstalled;
+use crate::config::{AliasMap, MissingRuntimeBehavior, Settings};
+use crate::errors::Error::{PluginNotInstalled, UnknownPlugin, UnknownVersion};
+use crate::errors::{suggestions, Error};
 use crate::file::changed_within;
 use crate::git::Git;
 use crate::hash::hash_to_str;
 use crate::plugins::script_manager::Script::ParseLegacyFile;
-use crate::shorthand::shorthand_to_repository;
+use crate::runtimes::EXEC_ENV_CACHE_FILENAME;
+use crate::shorthand::{shorthand_to_repository, SHORTHAND_MAP};
 use crate::ui::color::{cyan, Color};
 use crate::ui::prompt;
 use crate::ui::spinner::Spinner;
@@ -52,7 +54,10 @@ impl Plugin {
         Self {
             name: name.into(),
             cache_path: plugin_path.join(".rtxcache.msgpack.gz"),
-            script_man: ScriptManager::new(plugin_path.clone()),
+            script_man: ScriptManager::new(plugin_path.clone()).with_env(
+                "ASDF_PLUGIN_PATH".into(),
+                plugin_path.to_string_lossy().to_string(),
+            ),
             plugin_path,
             downloads_path: dirs::DOWNLOADS.join(name),
             installs_path: dirs::INSTALLS.join(name),
@@ -88,6 +93,67 @@ impl Plugin {
         self.plugin_path.exists()
     }
 
+    /// the raw output of one of the plugin's scripts, None if the plugin doesn't have it
+    pub fn read_script(&self, script: Script) -> Result<Option<String>> {
+        if !self.script_man.script_exists(&script) {
+            return Ok(None);
+        }
+        Ok(Some(self.script_man.read(script)?))
+    }
+
+    /// true if the plugin was added with `rtx plugins link`
+    pub fn is_linked(&self) -> bool {
+        self.plugin_path.is_symlink()
+    }
+
+    /// the directory a linked plugin points to
+    pub fn link_target(&self) -> Option<PathBuf> {
+        match self.is_linked() {
+            true => fs::read_link(&self.plugin_path).ok(),
+            false => None,
+        }
+    }
+
+    /// symlinks `path` into the plugins dir, replacing any existing plugin of the same name
+    /// versions installed with the existing plugin are kept
+    pub fn link(&self, path: &Path) -> Result<()> {
+        debug!("link {} to {}", self.name, path.display());
+        let path = path
+            .canonicalize()
+            .wrap_err_with(|| format!("{} does not exist", path.display()))?;
+        if !path.join("bin/list-all").exists() {
+            return Err(eyre!(
+                "{} is not a plugin: bin/list-all not found",
+                path.display()
+            ));
+        }
+        self.remove_plugin_dir()?;
+        fs::create_dir_all(&*dirs::PLUGINS)?;
+        std::os::unix::fs::symlink(&path, &self.plugin_path)?;
+        self.clear_exec_env_caches();
+        Ok(())
+    }
+
+    /// removes the symlink created by `rtx plugins link`, the linked directory is left alone
+    pub fn unlink(&self) -> Result<()> {
+        if !self.is_linked() {
+            return Err(eyre!("{} is not a linked plugin", self.name));
+        }
+        debug!("unlink {}", self.name);
+        remove_file(&self.plugin_path)?;
+        self.clear_exec_env_caches();
+        Ok(())
+    }
+
+    fn remove_plugin_dir(&self) -> Result<()> {
+        if self.is_linked() {
+            remove_file(&self.plugin_path)?;
+        } else if self.plugin_path.exists() {
+            fs::remove_dir_all(&self.plugin_path)?;
+        }
+        Ok(())
+    }
+
     pub fn get_remote_url(&self) -> Option<String> {
         let git = Git::new(self.plugin_path.to_path_buf());
         git.get_remote_url()
@@ -105,6 +171,10 @@ impl Plugin {
         let git = Git::new(self.plugin_path.to_path_buf());
         git.clone(repository)?;
         sp.success(format!("Plugin {} installed", cyan(Stderr, &self.name)));
+        self.script_man
+            .clone()
+            .with_env("ASDF_PLUGIN_SOURCE_URL".into(), repository.into())
+            .run_hook(Script::PostPluginAdd)?;
         Ok(())
     }
 
@@ -141,33 +211,44 @@ impl Plugin {
             },
             None => match settings.missing_runtime_behavior {
                 MissingRuntimeBehavior::Ignore => Ok(false),
-                _ => {
-                    warn!("Plugin not found: {}", COLOR.cyan(&self.name));
-                    Ok(false)
-                }
+                _ => Err(unknown_plugin(&self.name).into()),
             },
         }
     }
 
-    pub fn update(&self, gitref: Option<String>) -> Result<()> {
+    /// updates the plugin to `gitref` or the remote default branch and returns the commits that
+    /// were pulled in. With `dry_run` it only fetches and returns the commits that would be.
+    pub fn update(&self, gitref: Option<String>, dry_run: bool) -> Result<Vec<String>> {
         let plugin_path = self.plugin_path.to_path_buf();
         if plugin_path.is_symlink() {
             warn!("Plugin: {} is a symlink, not updating", self.name);
-            return Ok(());
+            return Ok(vec![]);
         }
         let git = Git::new(plugin_path);
         if !git.is_repo() {
             warn!("Plugin {} is not a git repository not updating", self.name);
-            return Ok(());
+            return Ok(vec![]);
         }
-        // TODO: asdf_run_hook "pre_plugin_update"
-        let (_pre, _post) = git.update(gitref)?;
-        // TODO: asdf_run_hook "post_plugin_update"
-        Ok(())
+        if dry_run {
+            return git.fetch_pending(gitref);
+        }
+        let (pre, post) = git.update(gitref)?;
+        // the versions, aliases, legacy filenames and exec-env output may have changed
+        self.clear_cache();
+        self.clear_exec_env_caches();
+        self.script_man
+            .clone()
+            .with_env("ASDF_PLUGIN_PREV_REF".into(), pre.clone())
+            .with_env("ASDF_PLUGIN_POST_REF".into(), post.clone())
+            .run_hook(Script::PostPluginUpdate)?;
+        git.log(&pre, &post)
     }
 
     pub fn uninstall(&self) -> Result<()> {
         debug!("uninstall {}", self.name);
+        if self.is_installed() {
+            self.script_man.run_hook(Script::PrePluginRemove)?;
+        }
 
         let rmdir = |dir: &Path| {
             if !dir.exists() {
@@ -183,12 +264,22 @@ impl Plugin {
 
         rmdir(&self.downloads_path)?;
         rmdir(&self.installs_path)?;
-        rmdir(&self.plugin_path)?;
+        self.remove_plugin_dir().wrap_err_with(|| {
+            format!(
+                "Failed to remove plugin {}",
+                cyan(Stderr, &self.plugin_path.to_string_lossy())
+            )
+        })?;
 
         Ok(())
     }
 
     pub fn latest_version(&self, query: &str) -> Result<Option<String>> {
+        Ok(self.find_latest_version(query)?.version)
+    }
+
+    /// the latest remote version matching `query` along with how it was picked
+    pub fn find_latest_version(&self, query: &str) -> Result<LatestVersion> {
         let mut query = query;
         if query == "latest" {
             query = "[0-9]";
@@ -199,17 +290,61 @@ impl Plugin {
             ).unwrap();
         }
         let query_regex = Regex::new((String::from(r"^\s*") + query).as_str())?;
-        let matches = self
-            .get_cache()?
-            .versions
+        let versions = self.get_cache()?.versions;
+        let (excluded, stable): (Vec<_>, Vec<_>) = versions
+            .iter()
+            .cloned()
+            .partition(|v| VERSION_REGEX.is_match(v));
+        let matches = stable
             .into_iter()
-            .filter(|v| !VERSION_REGEX.is_match(v))
             .filter(|v| query_regex.is_match(v))
             .collect_vec();
-        match matches.contains(&query.to_string()) {
-            true => Ok(Some(query.to_string())),
-            false => Ok(matches.last().map(|v| v.to_string())),
+        let version = match matches.contains(&query.to_string()) {
+            true => Some(query.to_string()),
+            false => matches.last().map(|v| v.to_string()),
+        };
+        Ok(LatestVersion {
+            query_regex: query_regex.to_string(),
+            versions,
+            excluded,
+            matches,
+            version,
+        })
+    }
+
+    /// like `latest_version` but uses the plugin's `bin/latest-stable` script if it has one
+    pub fn latest_stable_version(&self, query: &str) -> Result<Option<String>> {
+        let script = Script::LatestStable(match query {
+            "latest" => "[0-9]".into(),
+            query => query.into(),
+        });
+        if !self.script_man.script_exists(&script) {
+            return self.latest_version(query);
         }
+        let stdout = self.script_man.read(script)?;
+        Ok(stdout.lines().last().map(|v| v.trim().to_string()))
+    }
+
+    /// output of the `bin/help.*` scripts in the same layout as `asdf help <plugin>`
+    pub fn help(&self, version: Option<&str>) -> Result<String> {
+        if !self
+            .script_man
+            .script_exists(&Script::Help("overview".into()))
+        {
+            return Err(eyre!("no documentation for plugin {}", self.name));
+        }
+        let mut script_man = self.script_man.clone();
+        if let Some(version) = version {
+            script_man = script_man.with_env("ASDF_INSTALL_VERSION".into(), version.into());
+        }
+        let mut help = vec![];
+        for topic in ["overview", "deps", "config", "links"] {
+            let script = Script::Help(topic.into());
+            if script_man.script_exists(&script) {
+                help.push(script_man.read(script)?);
+            }
+        }
+        Ok(help.join("\n\n"))
     }
 
     pub fn legacy_filenames(&self) -> Result<Vec<String>> {
@@ -235,6 +370,33 @@ impl Plugin {
         Ok(cache.versions)
     }
 
+    /// an UnknownVersion error suggesting aliases with similar names and the latest versions
+    /// sharing the longest prefix with `version`, e.g.: 18.99 suggests 18.15.0
+    pub fn unknown_version(&self, version: &str, aliases: &AliasMap) -> Error {
+        let versions = self.get_cache().map(|c| c.versions).unwrap_or_default();
+        let common_prefix_len = |v: &String| {
+            v.chars()
+                .zip(version.chars())
+                .take_while(|(a, b)| a == b)
+                .count()
+        };
+        let longest = versions.iter().map(common_prefix_len).max().unwrap_or(0);
+        let mut similar = match longest {
+            0 => vec![],
+            _ => versions
+                .iter()
+                .filter(|v| common_prefix_len(v) == longest)
+                .rev()
+                .take(3)
+                .cloned()
+                .collect_vec(),
+        };
+        if let Some(aliases) = aliases.get(&self.name) {
+            similar.extend(suggestions(version, aliases.keys().map(|a| a.as_str())));
+        }
+        UnknownVersion(self.name.clone(), version.into(), similar)
+    }
+
     pub fn list_aliases(&self) -> Result<Vec<(String, String)>> {
         let cache = self.get_cache()?;
         Ok(cache.aliases)
@@ -282,8 +444,7 @@ impl Plugin {
     fn fetch_remote_versions(&self) -> Result<Vec<String>> {
         Ok(self
             .script_man
-            .cmd(Script::ListAll)
-            .read()?
+            .read(Script::ListAll)?
             .split_whitespace()
             .map(|v| v.into())
             .collect())
@@ -305,8 +466,7 @@ impl Plugin {
             return Err(PluginNotInstalled(self.name.clone()).into());
         }
         let cp = &self.cache_path;
-        // TODO: put this duration into settings
-        let pc = match cp.exists() && changed_within(cp, Duration::from_secs(60 * 60 * 24))? {
+        let pc = match self.is_cache_fresh()? {
             true => PluginCache::parse(cp)?,
             false => {
                 let pc = self.build_cache()?;
@@ -339,6 +499,17 @@ impl Plugin {
         })
     }
 
+    fn is_cache_fresh(&self) -> Result<bool> {
+        let cp = &self.cache_path;
+        // TODO: put this duration into settings
+        Ok(cp.exists() && changed_within(cp, Duration::from_secs(60 * 60 * 24))?)
+    }
+
+    /// true if the remote versions can be checked without running bin/list-all
+    pub fn has_cached_versions(&self) -> bool {
+        self.cache.is_some() || self.is_cache_fresh().unwrap_or(false)
+    }
+
     fn clear_cache(&self) {
         if self.cache_path.exists() {
             remove_file(&self.cache_path).unwrap_or_else(|e| {
@@ -347,6 +518,22 @@ impl Plugin {
         }
     }
 
+    /// removes the cached exec-env output of the installed versions since the scripts changed
+    fn clear_exec_env_caches(&self) {
+        let versions = file::dir_subdirs(&self.installs_path).unwrap_or_default();
+        for version in versions {
+            let path = self
+                .installs_path
+                .join(version)
+                .join(EXEC_ENV_CACHE_FILENAME);
+            if path.exists() {
+                remove_file(&path).unwrap_or_else(|e| {
+                    debug!("failed to remove exec-env cache file: {}", e);
+                });
+            }
+        }
+    }
+
     fn fetch_legacy_filenames(&self) -> Result<Vec<String>> {
         if !self.script_man.script_exists(&Script::ListLegacyFilenames) {
             return Ok(vec![]);
@@ -422,6 +609,29 @@ impl Plugin {
     }
 }
 
+/// how `Plugin::latest_version` picked a version from the remote versions
+#[derive(Debug)]
+pub struct LatestVersion {
+    pub query_regex: String,
+    /// every version from `bin/list-all`
+    pub versions: Vec<String>,
+    /// the versions which look like pre-releases and are never picked
+    pub excluded: Vec<String>,
+    /// the versions matching `query_regex`, the last one is picked unless one is an exact match
+    pub matches: Vec<String>,
+    pub version: Option<String>,
+}
+
+/// an UnknownPlugin error suggesting installed plugins and shorthands with similar names
+pub fn unknown_plugin(name: &str) -> Error {
+    let installed = file::dir_subdirs(&dirs::PLUGINS).unwrap_or_default();
+    let candidates = installed
+        .iter()
+        .map(|p| p.as_str())
+        .chain(SHORTHAND_MAP.keys().copied());
+    UnknownPlugin(name.into(), suggestions(name, candidates))
+}
+
 impl PartialEq for Plugin {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name
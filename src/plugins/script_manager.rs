@@ -1,5 +1,6 @@
 use std::fmt;
 use std::fmt::{Display, Formatter};
+use std::io;
 use std::io::{stderr, Write};
 use std::path::PathBuf;
 use std::process::Output;
@@ -13,6 +14,7 @@ use crate::cmd::cmd;
 use crate::env;
 use crate::errors::Error::ScriptFailed;
 use crate::file::basename;
+use crate::timings;
 
 #[derive(Debug, Clone)]
 pub struct ScriptManager {
@@ -33,12 +35,18 @@ pub enum Script {
     ListLegacyFilenames,
     ListAliases,
     ParseLegacyFile(String),
+    LatestStable(String),
+    Help(String),
+    PostPluginAdd,
+    PostPluginUpdate,
+    PrePluginRemove,
 
     // RuntimeVersion
     Download(InstallType),
     Install(InstallType),
     Uninstall,
     ListBinPaths,
+    ExecPath(String, String),
     // ExecEnv,
 }
 
@@ -50,11 +58,17 @@ impl fmt::Display for Script {
             Script::ListLegacyFilenames => write!(f, "list-legacy-filenames"),
             Script::ListAliases => write!(f, "list-aliases"),
             Script::ParseLegacyFile(_) => write!(f, "parse-legacy-file"),
+            Script::LatestStable(_) => write!(f, "latest-stable"),
+            Script::Help(topic) => write!(f, "help.{topic}"),
+            Script::PostPluginAdd => write!(f, "post-plugin-add"),
+            Script::PostPluginUpdate => write!(f, "post-plugin-update"),
+            Script::PrePluginRemove => write!(f, "pre-plugin-remove"),
 
             // RuntimeVersion
             Script::Install(_) => write!(f, "install"),
             Script::Uninstall => write!(f, "uninstall"),
             Script::ListBinPaths => write!(f, "list-bin-paths"),
+            Script::ExecPath(..) => write!(f, "exec-path"),
             // Script::ExecEnv => write!(f, "exec-env"),
             Script::Download(_) => write!(f, "download"),
         }
@@ -117,6 +131,15 @@ impl ScriptManager {
         let mut env = self.env.clone();
         let args = match &script {
             Script::ParseLegacyFile(filename) => vec![filename.clone()],
+            Script::LatestStable(query) => vec![query.clone()],
+            Script::ExecPath(cmd, executable_path) => vec![
+                self.env
+                    .get("ASDF_INSTALL_PATH")
+                    .cloned()
+                    .unwrap_or_default(),
+                cmd.clone(),
+                executable_path.clone(),
+            ],
             Script::Install(install_type) | Script::Download(install_type) => {
                 env.insert("ASDF_INSTALL_TYPE".to_string(), install_type.to_string());
                 vec![]
@@ -134,9 +157,35 @@ impl ScriptManager {
         cmd
     }
 
+    /// runs `script` with `run`, which should be unchecked so the exit code is included in
+    /// the timings
+    fn exec<F>(&self, script: Script, run: F) -> io::Result<Output>
+    where
+        F: FnOnce(Expression) -> io::Result<Output>,
+    {
+        let mut span = timings::span(script.to_string());
+        span.arg("plugin", &self.plugin_name);
+        let output = run(self.cmd(script));
+        if let Ok(Some(code)) = output.as_ref().map(|o| o.status.code()) {
+            span.arg("exit_code", code);
+        }
+        output
+    }
+
     pub fn run(&self, script: Script) -> Result<()> {
-        let cmd = self.cmd(script);
-        let Output { status, .. } = cmd.unchecked().run()?;
+        let Output { status, .. } = self.exec(script, |cmd| cmd.unchecked().run())?;
+
+        match status.success() {
+            true => Ok(()),
+            false => Err(ScriptFailed(self.plugin_name.clone(), Some(status)).into()),
+        }
+    }
+
+    /// runs the script with its output going to stderr so it doesn't end up in output that is
+    /// meant to be eval'd such as `rtx env`
+    pub fn run_to_stderr(&self, script: Script) -> Result<()> {
+        let Output { status, .. } =
+            self.exec(script, |cmd| cmd.stdout_to_stderr().unchecked().run())?;
 
         match status.success() {
             true => Ok(()),
@@ -144,22 +193,33 @@ impl ScriptManager {
         }
     }
 
+    /// runs a hook script like post-plugin-add if the plugin has it
+    pub fn run_hook(&self, script: Script) -> Result<()> {
+        if !self.script_exists(&script) {
+            return Ok(());
+        }
+        self.run_to_stderr(script)
+    }
+
+    /// runs the script and returns its stdout without trailing newlines
     pub fn read(&self, script: Script) -> Result<String> {
-        self.cmd(script)
-            .read()
-            .with_context(|| ScriptFailed(self.plugin_name.clone(), None))
+        let Output { status, stdout, .. } = self
+            .exec(script, |cmd| cmd.stdout_capture().unchecked().run())
+            .with_context(|| ScriptFailed(self.plugin_name.clone(), None))?;
+        if !status.success() {
+            return Err(ScriptFailed(self.plugin_name.clone(), Some(status)).into());
+        }
+        let stdout = String::from_utf8_lossy(&stdout);
+        Ok(stdout.trim_end_matches(['\n', '\r']).to_string())
     }
 
     pub fn run_with_hidden_output<F>(&self, script: Script, on_error: F) -> Result<()>
     where
         F: FnOnce(),
     {
-        let out = self
-            .cmd(script)
-            .stderr_to_stdout()
-            .stdout_capture()
-            .unchecked()
-            .run();
+        let out = self.exec(script, |cmd| {
+            cmd.stderr_to_stdout().stdout_capture().unchecked().run()
+        });
 
         match out {
             Err(err) => {
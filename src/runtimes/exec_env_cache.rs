@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+use std::fs;
+use std::fs::File;
+use std::path::Path;
+
+use serde_derive::{Deserialize, Serialize};
+
+/// stored in the install directory next to the runtimeconf
+pub const EXEC_ENV_CACHE_FILENAME: &str = ".rtxexecenv.msgpack";
+
+/// The output of a runtime's `bin/exec-env` script so it doesn't need to run on every prompt.
+/// It is only used if `key` matches, which is a hash of the script, its mtime, the runtime version
+/// and the env vars the script is likely to read.
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct ExecEnvCache {
+    pub key: String,
+    pub env: HashMap<String, String>,
+}
+
+impl ExecEnvCache {
+    pub fn parse(path: &Path) -> color_eyre::Result<Self> {
+        Ok(rmp_serde::from_read(File::open(path)?)?)
+    }
+
+    pub fn write(&self, path: &Path) -> color_eyre::Result<()> {
+        let bytes = rmp_serde::to_vec_named(self)?;
+        // other shells may be reading it at the same time
+        let tmp = path.with_extension(format!("{}", std::process::id()));
+        fs::write(&tmp, bytes)?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+}
@@ -1,28 +1,35 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use atty::Stream::Stderr;
 use std::fmt;
 use std::fmt::{Display, Formatter};
+use std::fs;
 use std::fs::{create_dir_all, remove_dir_all};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use color_eyre::eyre::{eyre, Result, WrapErr};
+use itertools::Itertools;
 use once_cell::sync::Lazy;
 use versions::Versioning;
 
+use exec_env_cache::ExecEnvCache;
+pub use exec_env_cache::EXEC_ENV_CACHE_FILENAME;
 use runtime_conf::RuntimeConf;
 
+use crate::binary_cache::BinaryCache;
 use crate::config::Config;
 use crate::config::{MissingRuntimeBehavior, Settings};
 use crate::env_diff::{EnvDiff, EnvDiffOperation};
 use crate::errors::Error::{PluginNotInstalled, VersionNotInstalled};
+use crate::hash::hash_to_str;
 use crate::plugins::{InstallType, Plugin, Script, ScriptManager};
 use crate::ui::color::{cyan, Color};
 use crate::ui::prompt;
 use crate::ui::spinner::Spinner;
-use crate::{dirs, env, fake_asdf, file};
+use crate::{dirs, env, fake_asdf, file, timings};
 
+mod exec_env_cache;
 mod runtime_conf;
 
 static COLOR: Lazy<Color> = Lazy::new(|| Color::new(Stderr));
@@ -41,8 +48,13 @@ pub struct RuntimeVersion {
 
 impl RuntimeVersion {
     pub fn new(plugin: Arc<Plugin>, version: &str) -> Self {
-        let install_path = dirs::INSTALLS.join(&plugin.name).join(version);
-        let download_path = dirs::DOWNLOADS.join(&plugin.name).join(version);
+        Self::new_in(plugin, version, &dirs::ROOT)
+    }
+
+    /// a runtime version which installs into `data_dir` instead of RTX_DATA_DIR
+    pub fn new_in(plugin: Arc<Plugin>, version: &str, data_dir: &Path) -> Self {
+        let install_path = data_dir.join("installs").join(&plugin.name).join(version);
+        let download_path = data_dir.join("downloads").join(&plugin.name).join(version);
         Self {
             runtime_conf_path: install_path.join(".rtxconf.msgpack"),
             script_man: build_script_man(
@@ -51,7 +63,7 @@ impl RuntimeVersion {
                 &install_path,
                 &download_path,
             ),
-            download_path: dirs::DOWNLOADS.join(&plugin.name).join(version),
+            download_path,
             install_path,
             version: version.into(),
             plugin,
@@ -82,20 +94,27 @@ impl RuntimeVersion {
             return Err(PluginNotInstalled(self.plugin.name.clone()).into());
         }
 
+        if let Some(cache) = BinaryCache::from_settings(settings) {
+            match self.pull_from_binary_cache(&cache, config) {
+                Ok(true) => {
+                    sp.success(format!("Runtime {rtv_label} installed from {cache}"));
+                    return Ok(());
+                }
+                Ok(false) => {}
+                Err(err) => warn!("{err:#}, building {self} instead"),
+            }
+        }
+
         self.create_install_dirs()?;
         let download = Script::Download(install_type.clone());
         let install = Script::Install(install_type);
 
         if self.script_man.script_exists(&download) {
             if settings.verbose {
-                self.script_man
-                    .cmd(download)
-                    .stdout_to_stderr()
-                    .run()
-                    .map_err(|err| {
-                        self.cleanup_install_dirs_on_error(settings);
-                        err
-                    })?;
+                self.script_man.run_to_stderr(download).map_err(|err| {
+                    self.cleanup_install_dirs_on_error(settings);
+                    err
+                })?;
             } else {
                 self.script_man.run_with_hidden_output(download, || {
                     self.cleanup_install_dirs_on_error(settings);
@@ -104,14 +123,10 @@ impl RuntimeVersion {
         }
 
         if settings.verbose {
-            self.script_man
-                .cmd(install)
-                .stdout_to_stderr()
-                .run()
-                .map_err(|err| {
-                    self.cleanup_install_dirs_on_error(settings);
-                    err
-                })?;
+            self.script_man.run_to_stderr(install).map_err(|err| {
+                self.cleanup_install_dirs_on_error(settings);
+                err
+            })?;
             self.cleanup_install_dirs(settings);
         } else {
             self.script_man.run_with_hidden_output(install, || {
@@ -125,20 +140,21 @@ impl RuntimeVersion {
         };
         conf.write(&self.runtime_conf_path)?;
 
-        // attempt to touch all the .tool-version files to trigger updates in hook-env
-        let mut touch_dirs = vec![dirs::ROOT.to_path_buf()];
-        touch_dirs.extend(config.config_files.iter().cloned());
-        for path in touch_dirs {
-            let err = file::touch_dir(&path);
-            if let Err(err) = err {
-                debug!("error touching config file: {:?} {:?}", path, err);
-            }
-        }
+        touch_config_files(config);
         sp.success(format!("Runtime {rtv_label} installed"));
 
         Ok(())
     }
 
+    /// installs this runtime from `cache`, returns false if it is not in the cache
+    pub fn pull_from_binary_cache(&self, cache: &BinaryCache, config: &Config) -> Result<bool> {
+        if !cache.pull(self)? {
+            return Ok(false);
+        }
+        touch_config_files(config);
+        Ok(true)
+    }
+
     pub fn list_bin_paths(&self) -> Result<Vec<PathBuf>> {
         if self.version == "system" {
             return Ok(vec![]);
@@ -154,6 +170,24 @@ impl RuntimeVersion {
         Ok(bin_paths)
     }
 
+    /// the executable to run for a bin found in the bin paths. This is normally `bin_path` itself
+    /// but plugins with a `bin/exec-path` script can point it somewhere else in the install.
+    pub fn exec_path(&self, bin_name: &str, bin_path: PathBuf) -> Result<PathBuf> {
+        let relative_path = match bin_path.strip_prefix(&self.install_path) {
+            Ok(path) => path.to_string_lossy().to_string(),
+            Err(_) => return Ok(bin_path),
+        };
+        let script = Script::ExecPath(bin_name.into(), relative_path);
+        if !self.script_man.script_exists(&script) {
+            return Ok(bin_path);
+        }
+        let output = self.script_man.read(script)?;
+        match output.trim() {
+            "" => Ok(bin_path),
+            path => Ok(self.install_path.join(path)),
+        }
+    }
+
     pub fn ensure_installed(&self, config: &Config) -> Result<bool> {
         if self.is_installed() || self.version == "system" {
             return Ok(true);
@@ -196,9 +230,8 @@ impl RuntimeVersion {
     pub fn uninstall(&self) -> Result<()> {
         debug!("uninstall {} {}", self.plugin.name, self.version);
         if self.plugin.plugin_path.join("bin/uninstall").exists() {
-            let err = self.script_man.run(Script::Uninstall);
-            if err.is_err() {
-                warn!("Failed to run uninstall script: {}", err.unwrap_err());
+            if let Err(err) = self.script_man.run(Script::Uninstall) {
+                warn!("Failed to run uninstall script: {}", err);
             }
         }
         let rmdir = |dir: &Path| {
@@ -213,20 +246,46 @@ impl RuntimeVersion {
             })
         };
         rmdir(&self.install_path)?;
-        let err = rmdir(&self.download_path);
-        if err.is_err() {
-            warn!("Failed to remove download directory: {}", err.unwrap_err());
+        if let Err(err) = rmdir(&self.download_path) {
+            warn!("Failed to remove download directory: {}", err);
         }
         Ok(())
     }
 
+    /// the env vars set by the plugin's `bin/exec-env` script, this is cached in the install
+    /// directory until the plugin's scripts or the env vars they use change
+    /// linked plugins are never cached since they are likely being worked on
     pub fn exec_env(&self) -> Result<HashMap<String, String>> {
         let script = self.plugin.plugin_path.join("bin/exec-env");
         if !self.is_installed() || !script.exists() {
             return Ok(HashMap::new());
         }
-        let ed = EnvDiff::from_bash_script(&script, &self.script_man.env)?;
-        let env = ed
+        if self.plugin.is_linked() {
+            return self.run_exec_env(&script);
+        }
+        let cache_path = self.install_path.join(EXEC_ENV_CACHE_FILENAME);
+        let key = self.exec_env_cache_key()?;
+        if let Ok(cache) = ExecEnvCache::parse(&cache_path) {
+            if cache.key == key {
+                trace!("using cached exec-env for {self}");
+                return Ok(cache.env);
+            }
+        }
+        let env = self.run_exec_env(&script)?;
+        let cache = ExecEnvCache { key, env };
+        if let Err(err) = cache.write(&cache_path) {
+            debug!("failed to write exec-env cache for {self}: {err:#}");
+        }
+        Ok(cache.env)
+    }
+
+    fn run_exec_env(&self, script: &Path) -> Result<HashMap<String, String>> {
+        let mut span = timings::span("exec-env");
+        span.arg("plugin", &self.plugin.name);
+        span.arg("version", &self.version);
+        let ed = EnvDiff::from_bash_script(script, &self.script_man.env)?;
+        drop(span);
+        let env: HashMap<String, String> = ed
             .to_patches()
             .into_iter()
             .filter_map(|p| match p {
@@ -238,10 +297,48 @@ impl RuntimeVersion {
         Ok(env)
     }
 
+    /// every file in the plugin's bin/ and lib/ dirs is included since exec-env often sources
+    /// helpers from them
+    /// only the env vars rtx sets for plugins and the ones the scripts reference are included,
+    /// otherwise vars like PWD which change on every prompt would invalidate it
+    /// PATH is never included since `rtx activate` changes it whenever the versions change
+    fn exec_env_cache_key(&self) -> Result<String> {
+        let mut scripts = vec![];
+        for dir in ["bin", "lib"] {
+            list_files(&self.plugin.plugin_path.join(dir), &mut scripts)?;
+        }
+        let scripts = scripts
+            .into_iter()
+            .sorted()
+            .map(|path| {
+                let contents = fs::read_to_string(&path).unwrap_or_default();
+                let mtime = path.metadata()?.modified()?;
+                Ok((path, mtime, contents))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let referenced = scripts
+            .iter()
+            .flat_map(|(_, _, contents)| {
+                regex!(r"\$\{?([A-Za-z_][A-Za-z0-9_]*)")
+                    .captures_iter(contents)
+                    .map(|c| c[1].to_string())
+            })
+            .collect::<HashSet<_>>();
+        let env = self
+            .script_man
+            .env
+            .iter()
+            .filter(|(k, _)| *k != "PATH")
+            .filter(|(k, _)| k.starts_with("ASDF_") || *k == "HOME" || referenced.contains(*k))
+            .sorted()
+            .collect_vec();
+        Ok(hash_to_str(&(scripts, &self.version, env)))
+    }
+
     fn get_bin_paths(&self) -> Result<Vec<String>> {
         let list_bin_paths = self.plugin.plugin_path.join("bin/list-bin-paths");
         if list_bin_paths.exists() {
-            let output = self.script_man.cmd(Script::ListBinPaths).read()?;
+            let output = self.script_man.read(Script::ListBinPaths)?;
             Ok(output.split_whitespace().map(|e| e.into()).collect())
         } else {
             Ok(vec!["bin".into()])
@@ -279,6 +376,33 @@ impl PartialEq for RuntimeVersion {
     }
 }
 
+/// attempt to touch all the .tool-version files to trigger updates in hook-env
+fn list_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    for entry in dir.read_dir()? {
+        let path = entry?.path();
+        if path.is_dir() {
+            list_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn touch_config_files(config: &Config) {
+    let mut touch_dirs = vec![dirs::ROOT.to_path_buf()];
+    touch_dirs.extend(config.config_files.iter().cloned());
+    for path in touch_dirs {
+        let err = file::touch_dir(&path);
+        if let Err(err) = err {
+            debug!("error touching config file: {:?} {:?}", path, err);
+        }
+    }
+}
+
 fn build_script_man(
     version: &str,
     plugin_path: &Path,
@@ -299,3 +423,57 @@ fn build_script_man(
         )
         .with_env("ASDF_CONCURRENCY".into(), num_cpus::get().to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::assert_cli;
+
+    use super::*;
+
+    #[test]
+    fn test_exec_env_cache() {
+        assert_cli!("install", "tiny@1.0.1");
+        let plugin = Arc::new(Plugin::load(&"tiny".into()).unwrap());
+        let rtv = RuntimeVersion::new(plugin, "1.0.1");
+        let cache_path = rtv.install_path.join(EXEC_ENV_CACHE_FILENAME);
+        let _ = fs::remove_file(&cache_path);
+
+        let env = rtv.exec_env().unwrap();
+        assert_eq!(env["JDXCODE_TINY"], "1.0.1");
+        assert!(cache_path.exists());
+        assert_eq!(rtv.exec_env().unwrap(), env);
+    }
+
+    #[test]
+    fn test_exec_env_cache_ignores_path() {
+        assert_cli!("install", "tiny@1.0.1");
+        let plugin = Arc::new(Plugin::load(&"tiny".into()).unwrap());
+        let mut rtv = RuntimeVersion::new(plugin, "1.0.1");
+        let key = rtv.exec_env_cache_key().unwrap();
+
+        rtv.script_man
+            .env
+            .insert("PATH".into(), "/some/other/bin:/usr/bin".into());
+        assert_eq!(rtv.exec_env_cache_key().unwrap(), key);
+
+        rtv.script_man
+            .env
+            .insert("HOME".into(), "/elsewhere".into());
+        assert_ne!(rtv.exec_env_cache_key().unwrap(), key);
+    }
+
+    #[test]
+    fn test_exec_env_cache_includes_lib() {
+        assert_cli!("install", "tiny@1.0.1");
+        let plugin = Arc::new(Plugin::load(&"tiny".into()).unwrap());
+        let rtv = RuntimeVersion::new(plugin, "1.0.1");
+        let key = rtv.exec_env_cache_key().unwrap();
+
+        let lib = rtv.plugin.plugin_path.join("lib");
+        create_dir_all(&lib).unwrap();
+        fs::write(lib.join("utils.bash"), "export JDXCODE_TINY_LIB=1\n").unwrap();
+        let new_key = rtv.exec_env_cache_key().unwrap();
+        remove_dir_all(&lib).unwrap();
+        assert_ne!(new_key, key);
+    }
+}
@@ -16,6 +16,29 @@ impl Shell for Bash {
             out.push_str(&format!("export PATH=\"{}:$PATH\"\n", dir.display()));
         }
         out.push_str(&formatdoc! {r#"
+            rtx() {{
+              local command
+              command="${{1:-}}"
+              if [ "$#" = 0 ]; then
+                command "{exe}"
+                return
+              fi
+              shift
+
+              case "$command" in
+              shell|sh)
+                # only the env var output needs to be evaluated, not --help
+                if [[ ! " $* " =~ " --help " ]] && [[ ! " $* " =~ " -h " ]]; then
+                  eval "$(command "{exe}" "$command" -s bash "$@")"
+                  return $?
+                fi
+                command "{exe}" "$command" "$@"
+                ;;
+              *)
+                command "{exe}" "$command" "$@"
+                ;;
+              esac
+            }}
             _rtx_hook() {{
               local previous_exit_status=$?;
               trap -- '' SIGINT;
@@ -34,6 +57,7 @@ impl Shell for Bash {
     fn deactivate(&self) -> String {
         formatdoc! {r#"
             unset _rtx_hook;
+            unset -f rtx;
         "#}
     }
 
@@ -21,6 +21,28 @@ impl Shell for Fish {
         // much of this is from direnv
         // https://github.com/direnv/direnv/blob/cb5222442cb9804b1574954999f6073cc636eff0/internal/cmd/shell_fish.go#L14-L36
         out.push_str(&formatdoc! {r#"
+            function rtx
+              if test (count $argv) -eq 0
+                command {exe}
+                return
+              end
+
+              set command $argv[1]
+              set -e argv[1]
+
+              switch "$command"
+              case shell sh
+                # only the env var output needs to be evaluated, not --help
+                if contains -- --help $argv; or contains -- -h $argv
+                  command {exe} "$command" $argv
+                else
+                  command {exe} "$command" -s fish $argv | source
+                end
+              case '*'
+                command {exe} "$command" $argv
+              end
+            end
+
             function __rtx_env_eval --on-event fish_prompt --description {description};
                 {exe} hook-env -s fish | source;
 
@@ -54,6 +76,7 @@ impl Shell for Fish {
           functions --erase __rtx_env_eval;
           functions --erase __rtx_env_eval_2;
           functions --erase __rtx_cd_hook;
+          functions --erase rtx;
         "#}
     }
 
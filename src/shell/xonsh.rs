@@ -8,8 +8,8 @@ use crate::shell::{is_dir_in_path, Shell};
 pub struct Xonsh {}
 
 use std::borrow::Cow;
-fn xonsh_escape_sq(input: &str) -> Cow<str> {
-    for (i, ch) in input.chars().enumerate() {
+fn xonsh_escape_sq(input: &str) -> Cow<'_, str> {
+    for (i, ch) in input.char_indices() {
         if xonsh_escape_char(ch).is_some() {
             let mut escaped_string = String::with_capacity(input.len());
 
@@ -67,6 +67,14 @@ impl Shell for Xonsh {
               execx($({exe} hook-env -s xonsh))
 
             XSH.builtins.events.on_pre_prompt(listen_prompt) # Activate hook: before showing the prompt
+
+            def _rtx(args): # `rtx shell` needs to modify the current session
+              if args and args[0] in ('shell', 'sh') and '--help' not in args and '-h' not in args:
+                execx($({exe} @(args[0]) -s xonsh @(args[1:])))
+              else:
+                ![{exe} @(args)]
+
+            XSH.aliases['rtx'] = _rtx
             "#});
 
         out
@@ -87,6 +95,7 @@ impl Shell for Xonsh {
                   if fn.__name__ == hook_fn:
                     hndl.remove(fn)
                     break
+            XSH.aliases.pop('rtx', None)
         "#}
     }
 
@@ -1,7 +1,7 @@
 // This file is generated by scripts/update-shorthand-repo.sh
 // Do not edit this file manually
 
-pub const SHORTHAND_LIST: [(&str, &str); 592] = [
+pub static SHORTHAND_LIST: [(&str, &str); 592] = [
     // rtx custom aliases
     ("node", "https://github.com/asdf-vm/asdf-nodejs.git"),
     // asdf original aliases from https://github.com/asdf-vm/asdf-plugins
@@ -0,0 +1,190 @@
+use std::cell::Cell;
+use std::fmt::Display;
+use std::fs;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use color_eyre::eyre::Result;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use serde_json::json;
+
+use crate::env;
+
+/// Records how long each phase of loading config and each plugin script takes when
+/// `--timings` or RTX_TIMINGS is set. Spans are recorded when they are dropped and nest
+/// under whichever span is open on the same thread.
+pub struct Span(Option<OpenSpan>);
+
+struct OpenSpan {
+    name: String,
+    args: Vec<(String, String)>,
+    start: Instant,
+    depth: usize,
+}
+
+struct Record {
+    name: String,
+    args: Vec<(String, String)>,
+    start: Duration,
+    duration: Duration,
+    depth: usize,
+    tid: u64,
+}
+
+static START: Lazy<Instant> = Lazy::new(Instant::now);
+static RECORDS: Lazy<Mutex<Vec<Record>>> = Lazy::new(Default::default);
+static NEXT_TID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+    static TID: u64 = NEXT_TID.fetch_add(1, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    env::RTX_TIMINGS.is_some()
+}
+
+/// starts a span which ends when the returned value is dropped, this is a no-op unless
+/// timings are enabled
+pub fn span(name: impl Into<String>) -> Span {
+    if !is_enabled() {
+        return Span(None);
+    }
+    Lazy::force(&START);
+    let depth = DEPTH.with(|d| d.replace(d.get() + 1));
+    Span(Some(OpenSpan {
+        name: name.into(),
+        args: vec![],
+        start: Instant::now(),
+        depth,
+    }))
+}
+
+/// times `f` as a span named `name`
+pub fn time<T>(name: &str, f: impl FnOnce() -> T) -> T {
+    let _span = span(name);
+    f()
+}
+
+impl Span {
+    pub fn arg(&mut self, key: &str, value: impl Display) {
+        if let Some(span) = self.0.as_mut() {
+            span.args.push((key.into(), value.to_string()));
+        }
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        let span = match self.0.take() {
+            Some(span) => span,
+            None => return,
+        };
+        DEPTH.with(|d| d.set(span.depth));
+        let record = Record {
+            name: span.name,
+            args: span.args,
+            start: span.start.duration_since(*START),
+            duration: span.start.elapsed(),
+            depth: span.depth,
+            tid: TID.with(|tid| *tid),
+        };
+        RECORDS.lock().unwrap().push(record);
+    }
+}
+
+/// prints the spans as a tree to stderr or, if RTX_TIMINGS is a path ending in .json,
+/// writes them there in the Chrome trace format which can be loaded in chrome://tracing
+pub fn report() -> Result<()> {
+    let dest = match env::RTX_TIMINGS.as_ref() {
+        Some(dest) => dest,
+        None => return Ok(()),
+    };
+    let mut records = RECORDS.lock().unwrap();
+    records.sort_by_key(|r| (r.tid, r.start));
+    if dest.ends_with(".json") {
+        fs::write(dest, chrome_trace(&records).to_string())?;
+        info!("timings written to {dest}");
+    } else {
+        eprint!("{}", render_tree(&records));
+    }
+    Ok(())
+}
+
+fn render_tree(records: &[Record]) -> String {
+    let mut output = String::new();
+    for (tid, records) in &records.iter().group_by(|r| r.tid) {
+        output.push_str(&format!("rtx timings (thread {tid}):\n"));
+        for r in records {
+            let args = r.args.iter().map(|(k, v)| format!(" {k}={v}")).join("");
+            output.push_str(&format!(
+                "{:indent$}{} {:.1}ms{args}\n",
+                "",
+                r.name,
+                r.duration.as_secs_f64() * 1000.0,
+                indent = (r.depth + 1) * 2
+            ));
+        }
+    }
+    output
+}
+
+fn chrome_trace(records: &[Record]) -> serde_json::Value {
+    let events = records
+        .iter()
+        .map(|r| {
+            json!({
+                "name": r.name,
+                "cat": "rtx",
+                "ph": "X",
+                "ts": r.start.as_micros() as u64,
+                "dur": r.duration.as_micros() as u64,
+                "pid": std::process::id(),
+                "tid": r.tid,
+                "args": r.args.iter()
+                    .map(|(k, v)| (k.clone(), json!(v)))
+                    .collect::<serde_json::Map<_, _>>(),
+            })
+        })
+        .collect_vec();
+    json!({ "traceEvents": events })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(name: &str, depth: usize, tid: u64) -> Record {
+        Record {
+            name: name.into(),
+            args: vec![("plugin".into(), "tiny".into())],
+            start: Duration::from_millis(1),
+            duration: Duration::from_micros(1500),
+            depth,
+            tid,
+        }
+    }
+
+    #[test]
+    fn test_render_tree() {
+        let records = vec![record("Config::load", 0, 1), record("list-all", 1, 1)];
+        assert_eq!(
+            render_tree(&records),
+            "rtx timings (thread 1):\n  Config::load 1.5ms plugin=tiny\n    list-all 1.5ms plugin=tiny\n"
+        );
+    }
+
+    #[test]
+    fn test_chrome_trace() {
+        let trace = chrome_trace(&[record("list-all", 0, 2)]);
+        let event = &trace["traceEvents"][0];
+        assert_eq!(event["name"], "list-all");
+        assert_eq!(event["ph"], "X");
+        assert_eq!(event["ts"], 1000);
+        assert_eq!(event["dur"], 1500);
+        assert_eq!(event["tid"], 2);
+        assert_eq!(event["args"]["plugin"], "tiny");
+    }
+}